@@ -157,6 +157,42 @@ impl BinaryOperation {
             _ => return None,
         })
     }
+
+    /// Returns the name of the method that invokes the operator, e.g. `add_wrapped` in `a.add_wrapped(b)`.
+    /// This is the inverse of [`BinaryOperation::from_symbol`]; `&&` and `||` have no method.
+    pub fn method_name(self) -> Option<&'static str> {
+        Some(match self {
+            Self::Add => "add",
+            Self::AddWrapped => "add_wrapped",
+            Self::BitwiseAnd => "and",
+            Self::Div => "div",
+            Self::DivWrapped => "div_wrapped",
+            Self::Eq => "eq",
+            Self::Gte => "gte",
+            Self::Gt => "gt",
+            Self::Lte => "lte",
+            Self::Lt => "lt",
+            Self::Mod => "mod",
+            Self::Mul => "mul",
+            Self::MulWrapped => "mul_wrapped",
+            Self::Nand => "nand",
+            Self::Neq => "neq",
+            Self::Nor => "nor",
+            Self::BitwiseOr => "or",
+            Self::Pow => "pow",
+            Self::PowWrapped => "pow_wrapped",
+            Self::Rem => "rem",
+            Self::RemWrapped => "rem_wrapped",
+            Self::Shl => "shl",
+            Self::ShlWrapped => "shl_wrapped",
+            Self::Shr => "shr",
+            Self::ShrWrapped => "shr_wrapped",
+            Self::Sub => "sub",
+            Self::SubWrapped => "sub_wrapped",
+            Self::Xor => "xor",
+            Self::And | Self::Or => return None,
+        })
+    }
 }
 
 /// A binary expression `left op right` of two operands separated by some operator.
@@ -21,12 +21,15 @@ use leo_span::Span;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
-/// An annotation, e.g. @program.
+/// An annotation, e.g. @program or @test(should_fail).
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct Annotation {
     // TODO: Consider using a symbol instead of an identifier.
     /// The name of the annotation.
     pub identifier: Identifier,
+    /// The arguments of the annotation, if any.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub arguments: Vec<AnnotationArgument>,
     /// A span locating where the annotation occurred in the source.
     pub span: Span,
     /// The ID of the node.
@@ -35,8 +38,49 @@ pub struct Annotation {
 
 simple_node_impl!(Annotation);
 
+/// An argument of an annotation, e.g. `should_fail` in `@test(should_fail)`.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum AnnotationArgument {
+    /// A name, e.g. `should_fail`.
+    Identifier(Identifier),
+    /// An integer without a type, e.g. `100_000` in `@max_constraints(100_000)`.
+    /// Its digits are kept without underscores.
+    Integer(String, #[serde(with = "leo_span::span_json")] Span, NodeID),
+}
+
+impl AnnotationArgument {
+    /// Returns the span of the argument.
+    pub fn span(&self) -> Span {
+        match self {
+            Self::Identifier(identifier) => identifier.span,
+            Self::Integer(_, span, _) => *span,
+        }
+    }
+
+    /// Returns the ID of the argument.
+    pub fn id(&self) -> NodeID {
+        match self {
+            Self::Identifier(identifier) => identifier.id,
+            Self::Integer(_, _, id) => *id,
+        }
+    }
+}
+
+impl fmt::Display for AnnotationArgument {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Identifier(identifier) => write!(f, "{identifier}"),
+            Self::Integer(value, _, _) => write!(f, "{value}"),
+        }
+    }
+}
+
 impl fmt::Display for Annotation {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "@{}", self.identifier)
+        write!(f, "@{}", self.identifier)?;
+        if !self.arguments.is_empty() {
+            write!(f, "({})", self.arguments.iter().map(|argument| argument.to_string()).collect::<Vec<_>>().join(", "))?;
+        }
+        Ok(())
     }
 }
@@ -116,6 +116,31 @@ impl Function {
         self.name() == sym::main
     }
 
+    /// Returns `true` if the function is annotated with `@test`.
+    pub fn is_test(&self) -> bool {
+        self.annotations.iter().any(|annotation| annotation.identifier.name == sym::test)
+    }
+
+    /// Returns `true` if the function is annotated with `@test(should_fail)`.
+    pub fn is_should_fail_test(&self) -> bool {
+        self.annotations.iter().any(|annotation| {
+            annotation.identifier.name == sym::test
+                && annotation.arguments.iter().any(|argument| {
+                    matches!(argument, AnnotationArgument::Identifier(identifier) if identifier.name == sym::should_fail)
+                })
+        })
+    }
+
+    /// Returns the constraint budget declared with `@max_constraints(n)`, if the function has one.
+    pub fn max_constraints(&self) -> Option<u64> {
+        let annotation =
+            self.annotations.iter().find(|annotation| annotation.identifier.name == sym::max_constraints)?;
+        match annotation.arguments.first() {
+            Some(AnnotationArgument::Integer(value, ..)) => value.parse().ok(),
+            _ => None,
+        }
+    }
+
     ///
     /// Private formatting method used for optimizing [fmt::Debug] and [fmt::Display] implementations.
     ///
@@ -14,10 +14,23 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{normalize_json_value, remove_key_from_json, Expression, Struct, Type};
+use crate::{
+    normalize_json_value,
+    remove_key_from_json,
+    Expression,
+    Function,
+    Input,
+    Literal,
+    Mode,
+    Node,
+    Struct,
+    Type,
+    UnaryOperation,
+};
 
 use super::*;
-use leo_errors::{AstError, Result};
+use leo_errors::{emitter::Handler, AstError, InputError, Result};
+use leo_span::symbol::with_session_globals;
 
 /// Input data which includes [`ProgramInput`].
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -36,39 +49,119 @@ impl InputData {
 /// into [`ProgramInput`].
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct InputAst {
+    /// The input files included by this one. Their sections are merged in by the parser.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub includes: Vec<Include>,
     pub sections: Vec<Section>,
 }
 
 impl InputAst {
     /// Returns all values of the input AST for execution with `leo run`.
-    pub fn program_inputs(&self, program_name: &str, structs: IndexMap<Symbol, Struct>) -> Vec<String> {
-        self.sections
+    /// The values are taken from the section for `function`, or from its named `variant` if one is given,
+    /// e.g. `[main.testnet]`.
+    /// Every value in the section is checked against the input of `function` with the same name,
+    /// and all mismatches are reported to the `handler` before an error is returned.
+    /// The values are returned in the order in which `function` declares its inputs.
+    pub fn program_inputs(
+        &self,
+        function: &Function,
+        variant: Option<Symbol>,
+        structs: IndexMap<Symbol, Struct>,
+        handler: &Handler,
+    ) -> Result<Vec<String>> {
+        let sections: Vec<&Section> = self
+            .sections
             .iter()
-            .filter(|section| section.name() == program_name)
-            .flat_map(|section| {
-                section.definitions.iter().map(|definition| match &definition.type_ {
-                    // Handle case where the input may be record.
-                    Type::Identifier(identifier) => {
-                        match structs.get(&identifier.name) {
-                            // TODO: Better error handling.
-                            None => panic!(
-                                "Input error: A struct or record declaration does not exist for {}.",
-                                identifier.name
-                            ),
-                            Some(struct_) => match struct_.is_record {
-                                false => definition.value.to_string(),
-                                true => match &definition.value {
-                                    // Print out the record interface with visibility.
-                                    Expression::Struct(struct_expression) => struct_expression.to_record_string(),
-                                    _ => panic!("Input error: Expected a struct expression."),
-                                },
-                            },
+            .filter(|section| section.name == function.identifier.name && section.variant == variant)
+            .collect();
+
+        // A named variant must be present in the input file.
+        if let (Some(variant), true) = (variant, sections.is_empty()) {
+            return Err(InputError::missing_section(format!("{}.{variant}", function.identifier)).into());
+        }
+
+        // Constant inputs are compiled into the program, so they are not passed to it.
+        let definitions: Vec<&Definition> = sections
+            .into_iter()
+            .flat_map(|section| section.definitions.iter())
+            .filter(|definition| definition.mode != Mode::Constant)
+            .collect();
+
+        // Match each definition to the function input with the same name.
+        let mut matched: Vec<Option<&Definition>> = vec![None; function.input.len()];
+        for definition in definitions.iter() {
+            match function.input.iter().position(|input| input.identifier().name == definition.name.name) {
+                None => handler.emit_err(InputError::unexpected_input(
+                    definition.name,
+                    function.identifier,
+                    definition.name.span,
+                )),
+                Some(index) if matched[index].is_some() => handler.emit_err(InputError::duplicate_input(
+                    definition.name,
+                    function.identifier,
+                    definition.name.span,
+                )),
+                Some(index) => {
+                    if let Input::Internal(input) = &function.input[index] {
+                        if !input.type_.eq_flat(&definition.type_) {
+                            // Point to the declaration of the input in the Leo source.
+                            let declared_at = with_session_globals(|s| s.source_map.span_to_location(input.span))
+                                .map_or_else(|| input.span.to_string(), |location| location.to_string());
+                            handler.emit_err(InputError::input_type_mismatch(
+                                input.identifier,
+                                &input.type_,
+                                &definition.type_,
+                                declared_at,
+                                definition.span,
+                            ))
                         }
                     }
-                    _ => definition.value.to_string(),
-                })
+                    matched[index] = Some(definition);
+                }
+            }
+        }
+
+        // Check that there is a definition for each function input.
+        for (input, definition) in function.input.iter().zip(matched.iter()) {
+            if definition.is_none() {
+                handler.emit_err(InputError::missing_input(input.identifier(), function.identifier, input.span()));
+            }
+        }
+
+        // Check that literals have the declared type, and that struct and record values match their declarations.
+        for definition in definitions.iter() {
+            check_value(&definition.value, &definition.type_, &structs, handler);
+        }
+
+        // The values are passed in the order of the function inputs.
+        let values = matched
+            .into_iter()
+            .flatten()
+            .filter_map(|definition| match &definition.type_ {
+                // Handle case where the input may be record.
+                Type::Identifier(identifier) => match structs.get(&identifier.name) {
+                    None => {
+                        handler.emit_err(InputError::undefined_struct(identifier.name, identifier.span));
+                        None
+                    }
+                    Some(struct_) => match struct_.is_record {
+                        false => Some(definition.value.to_string()),
+                        true => match &definition.value {
+                            // Print out the record interface with visibility.
+                            Expression::Struct(struct_expression) => Some(struct_expression.to_record_string()),
+                            value => {
+                                handler.emit_err(InputError::illegal_expression(value, value.span()));
+                                None
+                            }
+                        },
+                    },
+                },
+                _ => Some(definition.value.to_string()),
             })
-            .collect::<Vec<_>>()
+            .collect::<Vec<_>>();
+
+        handler.last_err().map_err(|e| *e)?;
+        Ok(values)
     }
 
     /// Serializes the `Input` into a JSON Value.
@@ -106,3 +199,63 @@ impl InputAst {
             .map_err(|e| AstError::failed_to_write_ast_to_json_file(&path, &e))?)
     }
 }
+
+/// Checks that every literal in `value` has the type declared for it,
+/// and that every struct value in `value` initializes exactly the members declared for its type.
+fn check_value(value: &Expression, type_: &Type, structs: &IndexMap<Symbol, Struct>, handler: &Handler) {
+    match (type_, value) {
+        (type_, Expression::Literal(literal)) => {
+            let received = match literal {
+                Literal::Address(..) => Type::Address,
+                Literal::Boolean(..) => Type::Boolean,
+                Literal::Field(..) => Type::Field,
+                Literal::Group(..) => Type::Group,
+                Literal::Integer(integer_type, ..) => Type::Integer(*integer_type),
+                Literal::Scalar(..) => Type::Scalar,
+                Literal::String(..) => Type::String,
+            };
+            if !type_.eq_flat(&received) {
+                handler.emit_err(InputError::unexpected_type(type_, received, literal.span()));
+            }
+        }
+        (type_, Expression::Unary(unary)) if unary.op == UnaryOperation::Negate => {
+            check_value(&unary.receiver, type_, structs, handler)
+        }
+        (Type::Array(array_type), Expression::Array(array)) => {
+            array.elements.iter().for_each(|element| check_value(element, array_type.element_type(), structs, handler))
+        }
+        (Type::Identifier(identifier), Expression::Struct(struct_expression)) => {
+            let struct_ = match structs.get(&identifier.name) {
+                Some(struct_) => struct_,
+                // Undeclared structs are reported separately.
+                None => return,
+            };
+            for member in struct_expression.members.iter() {
+                match struct_.members.iter().find(|declared| declared.name() == member.identifier.name) {
+                    Some(declared) => {
+                        if let Some(expression) = &member.expression {
+                            check_value(expression, &declared.type_, structs, handler);
+                        }
+                    }
+                    // Records carry a `_nonce` that is not part of their declaration.
+                    None if struct_.is_record && member.identifier.name == sym::_nonce => {}
+                    None => handler.emit_err(InputError::unknown_struct_member(
+                        identifier.name,
+                        member.identifier.name,
+                        member.span,
+                    )),
+                }
+            }
+            for declared in struct_.members.iter() {
+                if !struct_expression.members.iter().any(|member| member.identifier.name == declared.name()) {
+                    handler.emit_err(InputError::missing_struct_member(
+                        identifier.name,
+                        declared.name(),
+                        struct_expression.span,
+                    ));
+                }
+            }
+        }
+        _ => {}
+    }
+}
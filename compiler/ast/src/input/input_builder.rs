@@ -0,0 +1,152 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Function, GroupLiteral, Mode, NodeID, Type};
+
+use super::*;
+use std::fmt;
+
+/// Builds the inputs of a transition from Rust values, as an alternative to writing an input file.
+///
+/// ```ignore
+/// let inputs = InputBuilder::new()
+///     .input("amount", 5u64)
+///     .array("path", [1u8, 2u8, 3u8])
+///     .address("receiver", "aleo1...")
+///     .struct_("point", [("x", 1u8), ("y", 2u8)])
+///     .record("token", "{ owner: aleo1....private, amount: 5u64.private, _nonce: 0group.public }")
+///     .build(&function)?;
+/// ```
+///
+/// The builder does not know the declarations of structs and records, so only the values of other types are
+/// checked against their inputs. Structs and records are checked by snarkVM when the transition is run.
+#[derive(Clone, Debug, Default)]
+pub struct InputBuilder {
+    values: IndexMap<String, InputValue>,
+}
+
+impl InputBuilder {
+    /// Returns a new builder without any inputs.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the input `name` to `value`, replacing any previous value.
+    pub fn input(mut self, name: impl Into<String>, value: impl Into<InputValue>) -> Self {
+        self.values.insert(name.into(), value.into());
+        self
+    }
+
+    /// Sets the input `name` to an array of `values`, replacing any previous value.
+    pub fn array<T: Into<InputValue>>(self, name: impl Into<String>, values: impl IntoIterator<Item = T>) -> Self {
+        self.input(name, InputValue::Array(values.into_iter().map(Into::into).collect()))
+    }
+
+    /// Sets the input `name` to the address `address`, e.g. `aleo1...`, replacing any previous value.
+    pub fn address(self, name: impl Into<String>, address: impl Into<String>) -> Self {
+        self.input(name, InputValue::Address(address.into()))
+    }
+
+    /// Sets the input `name` to the field element `value`, written in decimal, replacing any previous value.
+    pub fn field_element(self, name: impl Into<String>, value: impl fmt::Display) -> Self {
+        self.input(name, InputValue::Field(value.to_string()))
+    }
+
+    /// Sets the input `name` to the group element `value`, written in decimal, replacing any previous value.
+    pub fn group(self, name: impl Into<String>, value: impl fmt::Display) -> Self {
+        self.input(name, InputValue::Group(GroupLiteral::Single(value.to_string(), Span::default(), NodeID::default())))
+    }
+
+    /// Sets the input `name` to the scalar `value`, written in decimal, replacing any previous value.
+    pub fn scalar(self, name: impl Into<String>, value: impl fmt::Display) -> Self {
+        self.input(name, InputValue::Scalar(value.to_string()))
+    }
+
+    /// Sets the input `name` to a struct with the given members, replacing any previous value.
+    pub fn struct_<N: Into<String>, T: Into<InputValue>>(
+        self,
+        name: impl Into<String>,
+        members: impl IntoIterator<Item = (N, T)>,
+    ) -> Self {
+        let members = members.into_iter().map(|(name, value)| (name.into(), value.into())).collect();
+        self.input(name, InputValue::Struct(members))
+    }
+
+    /// Sets the input `name` to the record `record`, written as snarkVM prints it, replacing any previous value.
+    /// A record output by a transition can be passed on as it is.
+    pub fn record(self, name: impl Into<String>, record: impl Into<String>) -> Self {
+        self.input(name, InputValue::Record(record.into()))
+    }
+
+    /// Returns the values for the inputs of `function`, in the order they are declared.
+    /// Every value is checked against the type of its input.
+    /// Constant inputs are compiled into the program, so they are not part of the result.
+    pub fn build(mut self, function: &Function) -> Result<Vec<String>> {
+        let mut values = Vec::new();
+        for input in function.input.iter().filter(|input| input.mode() != Mode::Constant) {
+            let name = input.identifier().to_string();
+            let value = self.values.shift_remove(&name).ok_or_else(|| {
+                InputError::invalid_builder_input(format!(
+                    "missing a value for input `{name}` of function `{}`",
+                    function.identifier
+                ))
+            })?;
+            let type_ = input.type_();
+            if !has_type(&value, &type_) {
+                return Err(InputError::invalid_builder_input(format!(
+                    "input `{name}` has type `{type_}`, but the value `{value}` does not"
+                ))
+                .into());
+            }
+            values.push(value.to_string());
+        }
+
+        if let Some(name) = self.values.keys().next() {
+            return Err(InputError::invalid_builder_input(format!(
+                "function `{}` does not have an input named `{name}`",
+                function.identifier
+            ))
+            .into());
+        }
+
+        Ok(values)
+    }
+}
+
+/// Returns `true` if `value` is a value of type `type_`.
+fn has_type(value: &InputValue, type_: &Type) -> bool {
+    match (value, type_) {
+        (InputValue::Address(address), Type::Address) => address.starts_with("aleo1"),
+        (InputValue::Boolean(_), Type::Boolean) => true,
+        (InputValue::Field(field), Type::Field) => is_decimal(field),
+        (InputValue::Group(GroupLiteral::Single(group, ..)), Type::Group) => is_decimal(group),
+        (InputValue::Group(_), Type::Group) => true,
+        (InputValue::Integer(integer_type, _), Type::Integer(expected)) => integer_type == expected,
+        (InputValue::Scalar(scalar), Type::Scalar) => is_decimal(scalar),
+        (InputValue::Array(elements), Type::Array(array_type)) => {
+            elements.len() == array_type.length()
+                && elements.iter().all(|element| has_type(element, array_type.element_type()))
+        }
+        // The members of structs and records are checked by snarkVM, against their declarations.
+        (InputValue::Struct(_) | InputValue::Record(_), Type::Identifier(_)) => true,
+        _ => false,
+    }
+}
+
+/// Returns `true` if `value` is a non-empty string of decimal digits.
+fn is_decimal(value: &str) -> bool {
+    !value.is_empty() && value.bytes().all(|byte| byte.is_ascii_digit())
+}
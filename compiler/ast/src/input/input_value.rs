@@ -17,6 +17,7 @@
 use crate::{Expression, GroupLiteral, IntegerType, Literal, Node, Type, UnaryOperation};
 use leo_errors::{InputError, LeoError, Result};
 
+use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -27,6 +28,13 @@ pub enum InputValue {
     Field(String),
     Group(GroupLiteral),
     Integer(IntegerType, String),
+    Scalar(String),
+    Array(Vec<InputValue>),
+    /// A struct, by the names of its members.
+    Struct(IndexMap<String, InputValue>),
+    /// A record, written as snarkVM prints it,
+    /// e.g. `{ owner: aleo1....private, amount: 5u64.private, _nonce: 0group.public }`.
+    Record(String),
 }
 
 impl TryFrom<(Type, Expression)> for InputValue {
@@ -50,6 +58,23 @@ impl TryFrom<(Type, Expression)> for InputValue {
                     return Err(InputError::unexpected_type(x, &y, y.span()).into());
                 }
             },
+            (Type::Array(array_type), Expression::Array(array)) => {
+                if array.elements.len() != array_type.length() {
+                    return Err(InputError::array_length_mismatch(
+                        array_type.length(),
+                        array.elements.len(),
+                        array.span,
+                    )
+                    .into());
+                }
+                Self::Array(
+                    array
+                        .elements
+                        .into_iter()
+                        .map(|element| InputValue::try_from((array_type.element_type().clone(), element)))
+                        .collect::<Result<Vec<_>>>()?,
+                )
+            }
             (type_, Expression::Unary(unary)) if unary.op == UnaryOperation::Negate => {
                 InputValue::try_from((type_, *unary.receiver))?
             }
@@ -63,9 +88,55 @@ impl fmt::Display for InputValue {
         match self {
             InputValue::Address(ref address) => write!(f, "{address}"),
             InputValue::Boolean(ref boolean) => write!(f, "{boolean}"),
-            InputValue::Group(ref group) => write!(f, "{group}"),
-            InputValue::Field(ref field) => write!(f, "{field}"),
-            InputValue::Integer(ref type_, ref number) => write!(f, "{number}{type_:?}"),
+            InputValue::Group(ref group) => write!(f, "{group}group"),
+            InputValue::Field(ref field) => write!(f, "{field}field"),
+            InputValue::Integer(ref type_, ref number) => write!(f, "{number}{type_}"),
+            InputValue::Scalar(ref scalar) => write!(f, "{scalar}scalar"),
+            InputValue::Array(ref elements) => {
+                write!(f, "[{}]", elements.iter().map(|element| element.to_string()).collect::<Vec<_>>().join(", "))
+            }
+            InputValue::Struct(ref members) => {
+                let members = members.iter().map(|(name, value)| format!("{name}: {value}")).collect::<Vec<_>>();
+                write!(f, "{{ {} }}", members.join(", "))
+            }
+            InputValue::Record(ref record) => write!(f, "{record}"),
         }
     }
 }
+
+impl From<bool> for InputValue {
+    fn from(value: bool) -> Self {
+        Self::Boolean(value)
+    }
+}
+
+macro_rules! input_value_from_integer {
+    ($($type_:ty => $integer_type:ident),* $(,)?) => {
+        $(
+            impl From<$type_> for InputValue {
+                fn from(value: $type_) -> Self {
+                    Self::Integer(IntegerType::$integer_type, value.to_string())
+                }
+            }
+        )*
+    };
+}
+
+input_value_from_integer!(
+    u8 => U8,
+    u16 => U16,
+    u32 => U32,
+    u64 => U64,
+    u128 => U128,
+    i8 => I8,
+    i16 => I16,
+    i32 => I32,
+    i64 => I64,
+    i128 => I128,
+);
+
+impl<T: Into<InputValue>> From<Vec<T>> for InputValue {
+    fn from(values: Vec<T>) -> Self {
+        Self::Array(values.into_iter().map(Into::into).collect())
+    }
+}
@@ -0,0 +1,263 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    ArrayExpression,
+    Expression,
+    Function,
+    GroupLiteral,
+    Identifier,
+    Literal,
+    NodeBuilder,
+    Struct,
+    StructExpression,
+    StructVariableInitializer,
+    Type,
+    UnaryExpression,
+    UnaryOperation,
+};
+
+use super::*;
+use serde_json::Value;
+
+/// Converts a JSON input file into an input AST.
+///
+/// The JSON input is an object with one entry per section, e.g. `"main"` or `"main.testnet"`,
+/// each mapping the names of the function's inputs to their values. A value is either a string
+/// holding a Leo literal of the declared type, such as `"1u32"`, or a JSON boolean, number, array,
+/// or object, which is interpreted according to the declared type of the input.
+///
+/// Errors name the JSON path of the offending value, e.g. `main.a[0].x`.
+pub fn json_input_to_ast(
+    json: &Value,
+    functions: &IndexMap<Symbol, Function>,
+    structs: &IndexMap<Symbol, Struct>,
+    node_builder: &NodeBuilder,
+) -> Result<InputAst> {
+    let sections = json.as_object().ok_or_else(|| invalid_json_input("", "expected an object of sections"))?;
+
+    let mut input_ast = InputAst { includes: Vec::new(), sections: Vec::new() };
+    for (section, values) in sections {
+        let path = member_path("", section);
+        let (name, variant) = match section.split_once('.') {
+            Some((name, variant)) => (name, Some(variant)),
+            None => (section.as_str(), None),
+        };
+        if !is_identifier(name) || !variant.map_or(true, is_identifier) {
+            return Err(invalid_json_input(&path, "expected a section named `function` or `function.variant`").into());
+        }
+        let function = functions
+            .get(&Symbol::intern(name))
+            .ok_or_else(|| invalid_json_input(&path, format!("there is no function named `{name}`")))?;
+        let values = values.as_object().ok_or_else(|| invalid_json_input(&path, "expected an object of inputs"))?;
+
+        if let Some(key) =
+            values.keys().find(|key| !function.input.iter().any(|input| input.identifier().name.to_string() == **key))
+        {
+            return Err(invalid_json_input(
+                &member_path(&path, key),
+                format!("function `{name}` does not have an input named `{key}`"),
+            )
+            .into());
+        }
+
+        // Definitions are listed in the order of the function's inputs.
+        let mut definitions = Vec::new();
+        for input in function.input.iter() {
+            let identifier = input.identifier();
+            if let Some(value) = values.get(&identifier.to_string()) {
+                let type_ = input.type_();
+                let value = json_value_to_expression(
+                    value,
+                    &type_,
+                    &member_path(&path, &identifier.to_string()),
+                    structs,
+                    node_builder,
+                )?;
+                definitions.push(Definition {
+                    mode: input.mode(),
+                    type_,
+                    name: Identifier { name: identifier.name, span: Span::default(), id: node_builder.next_id() },
+                    value,
+                    span: Span::default(),
+                });
+            }
+        }
+
+        input_ast.sections.push(Section {
+            name: Symbol::intern(name),
+            variant: variant.map(Symbol::intern),
+            definitions,
+            span: Span::default(),
+        });
+    }
+
+    Ok(input_ast)
+}
+
+/// Converts a single JSON value at `path` of type `type_` into the expression of an input value.
+fn json_value_to_expression(
+    value: &Value,
+    type_: &Type,
+    path: &str,
+    structs: &IndexMap<Symbol, Struct>,
+    node_builder: &NodeBuilder,
+) -> Result<Expression> {
+    let mismatch =
+        || invalid_json_input(path, format!("expected a value of type `{}`, found `{value}`", type_source(type_)));
+
+    Ok(match (value, type_) {
+        (Value::String(literal), _) => literal_expression(literal, type_, node_builder).ok_or_else(mismatch)?,
+        (Value::Bool(boolean), Type::Boolean) => {
+            Expression::Literal(Literal::Boolean(*boolean, Span::default(), node_builder.next_id()))
+        }
+        (Value::Number(number), Type::Integer(_) | Type::Field | Type::Group | Type::Scalar) if !number.is_f64() => {
+            literal_expression(&format!("{number}{type_}"), type_, node_builder).ok_or_else(mismatch)?
+        }
+        (Value::Array(elements), Type::Array(array_type)) => {
+            if elements.len() != array_type.length() {
+                return Err(invalid_json_input(
+                    path,
+                    format!("expected an array of {} elements, found {}", array_type.length(), elements.len()),
+                )
+                .into());
+            }
+            let elements = elements
+                .iter()
+                .enumerate()
+                .map(|(i, element)| {
+                    json_value_to_expression(
+                        element,
+                        array_type.element_type(),
+                        &format!("{path}[{i}]"),
+                        structs,
+                        node_builder,
+                    )
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Expression::Array(ArrayExpression { elements, span: Span::default(), id: node_builder.next_id() })
+        }
+        (Value::Object(members), Type::Identifier(identifier)) => {
+            let struct_ = structs.get(&identifier.name).ok_or_else(|| {
+                invalid_json_input(path, format!("a struct or record declaration does not exist for `{identifier}`"))
+            })?;
+            if let Some(member) =
+                struct_.members.iter().find(|member| !members.contains_key(&member.name().to_string()))
+            {
+                return Err(invalid_json_input(path, format!("missing member `{}`", member.name())).into());
+            }
+            let members = members
+                .iter()
+                .map(|(name, value)| {
+                    let type_ = match struct_.members.iter().find(|member| member.name().to_string() == *name) {
+                        Some(member) => member.type_.clone(),
+                        // Records carry a `_nonce` that is not part of their declaration.
+                        None if struct_.is_record && Symbol::intern(name) == sym::_nonce => Type::Group,
+                        None => {
+                            return Err(invalid_json_input(
+                                &member_path(path, name),
+                                format!("struct `{identifier}` does not have a member named `{name}`"),
+                            )
+                            .into());
+                        }
+                    };
+                    let expression =
+                        json_value_to_expression(value, &type_, &member_path(path, name), structs, node_builder)?;
+                    Ok(StructVariableInitializer {
+                        identifier: Identifier {
+                            name: Symbol::intern(name),
+                            span: Span::default(),
+                            id: node_builder.next_id(),
+                        },
+                        expression: Some(expression),
+                        span: Span::default(),
+                        id: node_builder.next_id(),
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Expression::Struct(StructExpression {
+                name: Identifier { name: identifier.name, span: Span::default(), id: node_builder.next_id() },
+                members,
+                span: Span::default(),
+                id: node_builder.next_id(),
+            })
+        }
+        _ => return Err(mismatch().into()),
+    })
+}
+
+/// Returns the expression of `literal` if it is a Leo literal of type `type_`, e.g. `1u32` or `-1i8`.
+fn literal_expression(literal: &str, type_: &Type, node_builder: &NodeBuilder) -> Option<Expression> {
+    // Negative values are negated literals, as in Leo source.
+    if let Some(receiver) = literal.strip_prefix('-') {
+        if !matches!(type_, Type::Integer(_) | Type::Field | Type::Group | Type::Scalar) || receiver.starts_with('-') {
+            return None;
+        }
+        return Some(Expression::Unary(UnaryExpression {
+            receiver: Box::new(literal_expression(receiver, type_, node_builder)?),
+            op: UnaryOperation::Negate,
+            span: Span::default(),
+            id: node_builder.next_id(),
+        }));
+    }
+
+    let (span, id) = (Span::default(), node_builder.next_id());
+    let literal = match type_ {
+        Type::Address => {
+            let is_address = literal.starts_with("aleo1")
+                && literal.bytes().all(|byte| byte.is_ascii_lowercase() || byte.is_ascii_digit());
+            Literal::Address(is_address.then(|| literal.to_string())?, span, id)
+        }
+        Type::Boolean => Literal::Boolean(literal.parse().ok()?, span, id),
+        Type::Integer(integer_type) => {
+            Literal::Integer(*integer_type, digits(literal.strip_suffix(&integer_type.to_string())?)?, span, id)
+        }
+        Type::Field => Literal::Field(digits(literal.strip_suffix("field")?)?, span, id),
+        Type::Group => {
+            Literal::Group(Box::new(GroupLiteral::Single(digits(literal.strip_suffix("group")?)?, span, id)))
+        }
+        Type::Scalar => Literal::Scalar(digits(literal.strip_suffix("scalar")?)?, span, id),
+        _ => return None,
+    };
+    Some(Expression::Literal(literal))
+}
+
+/// Returns `digits` if it is a non-empty string of decimal digits.
+fn digits(digits: &str) -> Option<String> {
+    (!digits.is_empty() && digits.bytes().all(|byte| byte.is_ascii_digit())).then(|| digits.to_string())
+}
+
+/// Returns whether `name` can be used as a section or variant name.
+fn is_identifier(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_alphabetic()) && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Returns the JSON path of the member `key` of the value at `path`.
+fn member_path(path: &str, key: &str) -> String {
+    match (path, is_identifier(key) || key == "_nonce") {
+        ("", true) => key.to_string(),
+        (_, true) => format!("{path}.{key}"),
+        (_, false) => format!("{path}[{key:?}]"),
+    }
+}
+
+/// Returns an error for the JSON value at `path`.
+fn invalid_json_input(path: &str, reason: impl std::fmt::Display) -> InputError {
+    match path {
+        "" => InputError::invalid_json_input(reason),
+        path => InputError::invalid_json_input(format!("at `{path}`, {reason}")),
+    }
+}
@@ -17,21 +17,52 @@
 pub mod definition;
 pub use definition::*;
 
+pub mod include;
+pub use include::*;
+
 pub mod input_ast;
 pub use input_ast::*;
 
+pub mod input_builder;
+pub use input_builder::*;
+
 pub mod input_value;
 pub use input_value::*;
 
+pub mod json_input;
+pub use json_input::*;
+
 pub mod program_input;
 pub use program_input::*;
 
 pub mod section;
 pub use section::*;
 
+pub mod skeleton;
+pub use skeleton::*;
+
+use crate::{Mode, Type};
+
 use indexmap::IndexMap;
 use leo_errors::{InputError, LeoError, Result};
 use leo_span::{sym, Span, Symbol};
 use serde::{Deserialize, Serialize};
 
 type Definitions = IndexMap<Symbol, InputValue>;
+
+/// Returns the Leo syntax of `type_`, as written in input files.
+fn type_source(type_: &Type) -> String {
+    match type_ {
+        Type::Boolean => "bool".to_string(),
+        Type::Array(array_type) => format!("[{}; {}]", type_source(array_type.element_type()), array_type.length()),
+        type_ => type_.to_string(),
+    }
+}
+
+/// Returns the Leo syntax of `mode` as it prefixes a definition in input files, e.g. `public `.
+fn mode_source(mode: Mode) -> String {
+    match mode {
+        Mode::Constant | Mode::Public => format!("{mode} "),
+        Mode::None | Mode::Private => String::new(),
+    }
+}
@@ -28,7 +28,8 @@ impl TryFrom<InputAst> for ProgramInput {
     fn try_from(input: InputAst) -> Result<Self> {
         let mut main = IndexMap::new();
 
-        for section in input.sections {
+        // Named variants of a section, e.g. `[main.testnet]`, are only used when selected explicitly.
+        for section in input.sections.into_iter().filter(|section| section.variant.is_none()) {
             let target = match section.name {
                 sym::main => &mut main,
                 _ => return Err(InputError::unexpected_section(&["main"], section.name, section.span).into()),
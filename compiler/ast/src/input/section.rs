@@ -17,10 +17,11 @@
 use super::*;
 
 /// A single section in an input or a state file.
-/// An example of a section would be: `[main]`.
+/// An example of a section would be: `[main]`, or `[main.testnet]` for a named variant of it.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Section {
     pub name: Symbol,
+    pub variant: Option<Symbol>,
     pub definitions: Vec<Definition>,
     pub span: Span,
 }
@@ -0,0 +1,72 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Function, Struct, Type, Variant};
+
+use super::*;
+
+/// The address whose bits are all zero, used as the default value of `address` inputs.
+const ZERO_ADDRESS: &str = "aleo1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq3ljyzc";
+
+/// Returns the source of an input file with a `[name]` section for each transition in `functions`.
+///
+/// Every input is defined with its declared mode, name, and type, and a zero value:
+/// `0` for numeric types, `false` for booleans, the zero address, and zero members for structs and records.
+/// Inputs whose type has no zero value are written as comments, to be filled in by hand.
+pub fn input_file_skeleton(functions: &IndexMap<Symbol, Function>, structs: &IndexMap<Symbol, Struct>) -> String {
+    let mut source = String::new();
+    for function in functions.values().filter(|function| function.variant == Variant::Transition) {
+        if !source.is_empty() {
+            source.push('\n');
+        }
+        source.push_str(&format!("[{}]\n", function.identifier));
+        for input in function.input.iter() {
+            let type_ = input.type_();
+            let definition = format!("{}{}: {}", mode_source(input.mode()), input.identifier(), type_source(&type_));
+            match zero_value(&type_, structs) {
+                Some(value) => source.push_str(&format!("{definition} = {value};\n")),
+                None => source.push_str(&format!("// {definition} = ?;\n")),
+            }
+        }
+    }
+    source
+}
+
+/// Returns the Leo syntax of the zero value of `type_`, if it has one.
+fn zero_value(type_: &Type, structs: &IndexMap<Symbol, Struct>) -> Option<String> {
+    Some(match type_ {
+        Type::Address => ZERO_ADDRESS.to_string(),
+        Type::Boolean => "false".to_string(),
+        Type::Integer(_) | Type::Field | Type::Group | Type::Scalar => format!("0{type_}"),
+        Type::Array(array_type) => {
+            format!("[{}; {}]", zero_value(array_type.element_type(), structs)?, array_type.length())
+        }
+        Type::Identifier(identifier) => {
+            let struct_ = structs.get(&identifier.name)?;
+            let mut members = struct_
+                .members
+                .iter()
+                .map(|member| Some(format!("{}: {}", member.name(), zero_value(&member.type_, structs)?)))
+                .collect::<Option<Vec<_>>>()?;
+            // Records carry a `_nonce` that is not part of their declaration.
+            if struct_.is_record {
+                members.push(format!("{}: 0group", sym::_nonce));
+            }
+            format!("{{ {} }}", members.join(", "))
+        }
+        Type::Mapping(_) | Type::Signature | Type::String | Type::Tuple(_) | Type::Unit | Type::Err => return None,
+    })
+}
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{GroupLiteral, Identifier, IntegerType, Literal, NodeID, Type};
+use crate::{ArrayType, GroupLiteral, Identifier, IntegerType, Literal, TupleType, Type};
 
 use leo_errors::{type_name, FlattenError, LeoError, Result};
 use leo_span::{Span, Symbol};
@@ -73,7 +73,7 @@ macro_rules! implement_const_unary {
     ) => {
         // TODO: This is temporary since the currently unused code is used in constant folding.
         #[allow(dead_code)]
-        pub(crate) fn $name(self, span: Span) -> Result<Self> {
+        pub fn $name(self, span: Span) -> Result<Self> {
             use Value::*;
 
             match self {
@@ -159,7 +159,7 @@ macro_rules! implement_const_binary {
     ) => {
         // This is temporary since the currently unused code is used in constant folding.
         #[allow(dead_code)]
-        pub(crate) fn $name(self, other: Self, span: Span) -> Result<Self> {
+        pub fn $name(self, other: Self, span: Span) -> Result<Self> {
             use Value::*;
 
             match (self, other) {
@@ -199,6 +199,8 @@ pub enum Value {
     U128(u128, Span),
     Scalar(String, Span),
     String(String, Span),
+    Tuple(Vec<Value>),
+    Array(Vec<Value>),
 }
 
 impl Value {
@@ -566,6 +568,43 @@ impl Value {
         ]
     );
 
+    implement_const_binary!(
+        @overflowing
+        name: rem,
+        method: checked_rem,
+        string: "%",
+        patterns: [
+            [I8, [I8], I8, i8, i8],
+            [I16, [I16], I16, i16, i16],
+            [I32, [I32], I32, i32, i32],
+            [I64, [I64], I64, i64, i64],
+            [I128, [I128], I128, i128, i128],
+            [U8, [U8], U8, u8, u8],
+            [U16, [U16], U16, u16, u16],
+            [U32, [U32], U32, u32, u32],
+            [U64, [U64], U64, u64, u64],
+            [U128, [U128], U128, u128, u128]
+        ]
+    );
+
+    implement_const_binary!(
+        @non-overflowing
+        name: rem_wrapped,
+        method: wrapping_rem,
+        patterns: [
+            [I8, [I8], I8, i8, i8],
+            [I16, [I16], I16, i16, i16],
+            [I32, [I32], I32, i32, i32],
+            [I64, [I64], I64, i64, i64],
+            [I128, [I128], I128, i128, i128],
+            [U8, [U8], U8, u8, u8],
+            [U16, [U16], U16, u16, u16],
+            [U32, [U32], U32, u32, u32],
+            [U64, [U64], U64, u64, u64],
+            [U128, [U128], U128, u128, u128]
+        ]
+    );
+
     implement_const_binary!(
         @overflowing
         name: shl,
@@ -704,7 +743,7 @@ impl Value {
 
     // TODO: This is temporary since the currently unused code is used in constant folding.
     #[allow(dead_code)]
-    pub(crate) fn is_supported_const_fold_type(&self) -> bool {
+    pub fn is_supported_const_fold_type(&self) -> bool {
         use Value::*;
         matches!(
             self,
@@ -745,6 +784,8 @@ impl Display for Value {
             U128(val, _) => write!(f, "{val}"),
             Scalar(val, _) => write!(f, "{val}"),
             String(val, _) => write!(f, "{val}"),
+            Tuple(values) => write!(f, "({})", values.iter().map(|value| value.to_string()).collect::<Vec<_>>().join(", ")),
+            Array(values) => write!(f, "[{}]", values.iter().map(|value| value.to_string()).collect::<Vec<_>>().join(", ")),
         }
     }
 }
@@ -861,6 +902,12 @@ impl From<&Value> for Type {
             U128(_, _) => Type::Integer(IntegerType::U128),
             Scalar(_, _) => Type::Scalar,
             String(_, _) => Type::String,
+            Tuple(values) => match values.is_empty() {
+                true => Type::Unit,
+                false => Type::Tuple(TupleType::new(values.iter().map(Type::from).collect())),
+            },
+            // Note that arrays are never empty, since the type checker rejects arrays of length zero.
+            Array(values) => Type::Array(ArrayType::new(Type::from(&values[0]), values.len().into())),
         }
     }
 }
@@ -895,30 +942,3 @@ impl TryFrom<&Literal> for Value {
         })
     }
 }
-
-impl Literal {
-    #[allow(unused)]
-    fn from_value(v: Value, id: NodeID) -> Self {
-        use Value::*;
-        match v {
-            Input(_, _) => todo!("We need to test if this is hittable"),
-            Address(v, span) => Literal::Address(v, span, id),
-            Boolean(v, span) => Literal::Boolean(v, span, id),
-            Struct(_ident, _values) => todo!("We need to test if this is hittable"),
-            Field(v, span) => Literal::Field(v, span, id),
-            Group(v) => Literal::Group(v),
-            I8(v, span) => Literal::Integer(IntegerType::I8, v.to_string(), span, id),
-            I16(v, span) => Literal::Integer(IntegerType::I16, v.to_string(), span, id),
-            I32(v, span) => Literal::Integer(IntegerType::I32, v.to_string(), span, id),
-            I64(v, span) => Literal::Integer(IntegerType::I64, v.to_string(), span, id),
-            I128(v, span) => Literal::Integer(IntegerType::I128, v.to_string(), span, id),
-            U8(v, span) => Literal::Integer(IntegerType::U8, v.to_string(), span, id),
-            U16(v, span) => Literal::Integer(IntegerType::U16, v.to_string(), span, id),
-            U32(v, span) => Literal::Integer(IntegerType::U32, v.to_string(), span, id),
-            U64(v, span) => Literal::Integer(IntegerType::U64, v.to_string(), span, id),
-            U128(v, span) => Literal::Integer(IntegerType::U128, v.to_string(), span, id),
-            Scalar(v, span) => Literal::Scalar(v, span, id),
-            String(v, span) => Literal::String(v, span, id),
-        }
-    }
-}
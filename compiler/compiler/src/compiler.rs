@@ -139,13 +139,8 @@ impl<'a> Compiler<'a> {
     /// Parses and stores the input file, constructs a syntax tree, and generates a program input.
     pub fn parse_input(&mut self, input_file_path: PathBuf) -> Result<()> {
         if input_file_path.exists() {
-            // Load the input file into the source map.
-            let input_sf = with_session_globals(|s| s.source_map.load_file(&input_file_path))
-                .map_err(|e| CompilerError::file_read_error(&input_file_path, e))?;
-
-            // Parse and serialize it.
-            let input_ast =
-                leo_parser::parse_input(self.handler, &self.node_builder, &input_sf.src, input_sf.start_pos)?;
+            // Parse the input file and the files it includes, and serialize it.
+            let input_ast = leo_parser::parse_input_file(self.handler, &self.node_builder, &input_file_path)?;
             if self.compiler_options.output.initial_ast {
                 // Write the input AST snapshot post parsing.
                 if self.compiler_options.output.ast_spans_enabled {
@@ -167,6 +162,30 @@ impl<'a> Compiler<'a> {
         Ok(())
     }
 
+    /// Returns the node builder of the program.
+    /// An input AST stored in `input_ast` must be built with it, since its constant values are compiled into the program.
+    pub fn node_builder(&self) -> &NodeBuilder {
+        &self.node_builder
+    }
+
+    /// Returns the types that the type checker recorded for the expressions of the program.
+    pub fn type_table(&self) -> &TypeTable {
+        &self.type_table
+    }
+
+    /// Runs the constant inputs pass.
+    /// Without an input file, constant inputs have no values, so they are reported.
+    pub fn constant_inputs_pass(&mut self) -> Result<()> {
+        let no_input = InputAst { includes: Vec::new(), sections: Vec::new() };
+        self.ast = ConstantInputInliner::do_pass((
+            std::mem::take(&mut self.ast),
+            self.input_ast.as_ref().unwrap_or(&no_input),
+            self.handler,
+            &self.node_builder,
+        ))?;
+        Ok(())
+    }
+
     /// Runs the symbol table pass.
     pub fn symbol_table_pass(&self) -> Result<SymbolTable> {
         let symbol_table = SymbolTableCreator::do_pass((&self.ast, self.handler))?;
@@ -186,6 +205,11 @@ impl<'a> Compiler<'a> {
         Ok((symbol_table, struct_graph, call_graph))
     }
 
+    /// Runs the linter pass, and returns the number of lints that were denied.
+    pub fn linter_pass(&self, symbol_table: &SymbolTable, config: &LintConfig) -> Result<usize> {
+        Linter::do_pass((&self.ast, self.handler, symbol_table, &self.type_table, config))
+    }
+
     /// Runs the loop unrolling pass.
     pub fn loop_unrolling_pass(&mut self, symbol_table: SymbolTable) -> Result<SymbolTable> {
         let (ast, symbol_table) = Unroller::do_pass((
@@ -301,6 +325,8 @@ impl<'a> Compiler<'a> {
 
     /// Runs the compiler stages.
     pub fn compiler_stages(&mut self) -> Result<(SymbolTable, StructGraph, CallGraph)> {
+        self.constant_inputs_pass()?;
+
         let st = self.symbol_table_pass()?;
         let (st, struct_graph, call_graph) = self.type_checker_pass(st)?;
 
@@ -317,6 +343,12 @@ impl<'a> Compiler<'a> {
 
         self.dead_code_elimination_pass()?;
 
+        // Transitions annotated with `@test` are only compiled when tests are enabled.
+        // They are removed after the other passes, which expect every function in the symbol table to be in the AST.
+        if !self.compiler_options.build.tests_enabled {
+            self.ast.ast.program_scopes.values_mut().for_each(|scope| scope.functions.retain(|(_, f)| !f.is_test()));
+        }
+
         Ok((st, struct_graph, call_graph))
     }
 
@@ -324,6 +356,44 @@ impl<'a> Compiler<'a> {
     pub fn compile(&mut self) -> Result<(SymbolTable, String)> {
         // Parse the program.
         self.parse_program()?;
+        // Run the compiler stages and code generation.
+        self.compile_parsed_program()
+    }
+
+    /// Returns a compiled Leo program from its source text, without reading the main file from disk.
+    /// Note that nothing is written to the output directory unless a snapshot is enabled in the compiler options.
+    pub fn compile_from_string(&mut self, program_string: &str, name: FileName) -> Result<(SymbolTable, String)> {
+        // Parse the program.
+        self.parse_program_from_string(program_string, name)?;
+        // Run the compiler stages and code generation.
+        self.compile_parsed_program()
+    }
+
+    /// Parses and statically checks a Leo program from its source text, without compiling it.
+    /// Errors and warnings are emitted to the handler, and the first error that stops the check is returned.
+    pub fn check_from_string(&mut self, program_string: &str, name: FileName) -> Result<SymbolTable> {
+        // Parse the program.
+        self.parse_program_from_string(program_string, name)?;
+        // Run the symbol table and type checker passes.
+        let symbol_table = self.symbol_table_pass()?;
+        let (symbol_table, ..) = self.type_checker_pass(symbol_table)?;
+        Ok(symbol_table)
+    }
+
+    /// Parses, type checks, and lints the main file of a Leo program, and returns the number of lints that were denied.
+    /// The lints are emitted to the handler as warnings.
+    pub fn lint(&mut self, config: &LintConfig) -> Result<usize> {
+        // Parse the program.
+        self.parse_program()?;
+        // Run the symbol table and type checker passes.
+        let symbol_table = self.symbol_table_pass()?;
+        let (symbol_table, ..) = self.type_checker_pass(symbol_table)?;
+        // Run the linter pass.
+        self.linter_pass(&symbol_table, config)
+    }
+
+    /// Runs the compiler stages and code generation on the parsed program.
+    pub fn compile_parsed_program(&mut self) -> Result<(SymbolTable, String)> {
         // Run the intermediate compiler stages.
         let (symbol_table, struct_graph, call_graph) = self.compiler_stages()?;
         // Run code generation.
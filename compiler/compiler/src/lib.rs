@@ -14,7 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-#![forbid(unsafe_code)]
+// The bindings generated by `wasm_bindgen` use unsafe code.
+#![cfg_attr(not(feature = "wasm"), forbid(unsafe_code))]
+#![cfg_attr(feature = "wasm", deny(unsafe_code))]
 #![allow(clippy::module_inception)]
 #![allow(clippy::upper_case_acronyms)]
 #![doc = include_str!("../README.md")]
@@ -24,3 +26,7 @@ pub use compiler::*;
 
 mod options;
 pub use options::*;
+
+#[cfg(feature = "wasm")]
+#[allow(unsafe_code)]
+pub mod wasm;
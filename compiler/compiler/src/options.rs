@@ -28,6 +28,8 @@ pub struct CompilerOptions {
 pub struct BuildOptions {
     /// Whether to enable dead code elimination.
     pub dce_enabled: bool,
+    /// Whether to compile the transitions annotated with `@test`.
+    pub tests_enabled: bool,
 }
 
 #[derive(Clone, Default)]
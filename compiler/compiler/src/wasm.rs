@@ -0,0 +1,60 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A JavaScript API to the front end of the compiler, so that diagnostics can be computed in the browser.
+//!
+//! Build it with `cargo build -p leo-compiler --target wasm32-unknown-unknown --features wasm`.
+
+use crate::Compiler;
+
+use leo_errors::{emitter::Handler, LeoError};
+use leo_span::{source_map::FileName, symbol::create_session_if_not_set_then};
+
+use serde_json::json;
+use std::path::PathBuf;
+use wasm_bindgen::prelude::*;
+
+/// Parses and type checks the program, and returns its diagnostics as a JSON string of the form
+/// `{ "errors": [...], "warnings": [...] }`, where each message is a `leo_errors::Diagnostic`.
+/// The name of the program is that of its program scope, e.g. `token` for `program token.aleo`.
+#[wasm_bindgen]
+pub fn check(program_name: &str, source: &str) -> String {
+    create_session_if_not_set_then(|_| {
+        let (handler, buf) = Handler::new_with_buf();
+        let mut compiler =
+            Compiler::new(program_name.to_string(), "aleo".to_string(), &handler, PathBuf::new(), PathBuf::new(), None);
+
+        // A pass that emitted its errors returns `LastErrorCode`, while any other error has not been emitted yet.
+        match compiler.check_from_string(source, FileName::Custom(format!("{program_name}.leo"))) {
+            Ok(_) | Err(LeoError::LastErrorCode(_)) => {}
+            Err(error) => handler.emit_err(error),
+        }
+
+        let errors = buf
+            .extract_errs()
+            .into_inner()
+            .into_iter()
+            .filter_map(|error| error.diagnostic())
+            .collect::<Vec<_>>();
+        let warnings = buf
+            .extract_warnings()
+            .into_inner()
+            .into_iter()
+            .map(|warning| warning.diagnostic())
+            .collect::<Vec<_>>();
+        json!({ "errors": errors, "warnings": warnings }).to_string()
+    })
+}
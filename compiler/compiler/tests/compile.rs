@@ -22,14 +22,20 @@ use utilities::{
     hash_asts,
     hash_content,
     hash_symbol_tables,
+    new_compiler,
     parse_program,
     setup_build_directory,
     BufferEmitter,
 };
 
-use leo_compiler::{CompilerOptions, OutputOptions};
+use leo_ast::{InputAst, Program, Struct, Variant};
+use leo_compiler::{Compiler, CompilerOptions, OutputOptions};
 use leo_errors::{emitter::Handler, LeoError};
-use leo_span::symbol::create_session_if_not_set_then;
+use leo_span::{
+    source_map::FileName,
+    symbol::{create_session_if_not_set_then, with_session_globals},
+    Symbol,
+};
 use leo_test_framework::{
     runner::{Namespace, ParseType, Runner},
     Test,
@@ -37,6 +43,7 @@ use leo_test_framework::{
 
 use snarkvm::prelude::*;
 
+use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 use serde_yaml::Value;
 use std::{fs, path::Path, rc::Rc};
@@ -106,10 +113,23 @@ fn run_test(test: Test, handler: &Handler, buf: &BufferEmitter) -> Result<Value,
         let mut parsed =
             handler.extend_if_error(parse_program(handler, &test.content, cwd.clone(), Some(compiler_options)))?;
 
+        // Compile the constant values of the input file, if one is given, into the program.
+        // The errors are emitted to the handler.
+        parsed.input_ast = parse_input_file(&test, &parsed, handler)?;
+        parsed.constant_inputs_pass().map_err(|_| ())?;
+
+        // Keep the function signatures that the input file is checked against, since compilation changes the program.
+        let (program, input_ast) = (parsed.ast.ast.clone(), parsed.input_ast.clone());
+
         // Compile the program to bytecode.
         let program_name = format!("{}.{}", parsed.program_name, parsed.network);
         let bytecode = handler.extend_if_error(compile_and_process(&mut parsed))?;
 
+        // Check the input file against the transitions of the program.
+        if let Some(input_ast) = input_ast {
+            check_input_file(&program, &input_ast, handler)?;
+        }
+
         // Set up the build directory.
         // Note that this function checks that the bytecode is well-formed.
         let package = setup_build_directory(&program_name, &bytecode, handler)?;
@@ -148,6 +168,38 @@ fn run_test(test: Test, handler: &Handler, buf: &BufferEmitter) -> Result<Value,
     Ok(serde_yaml::to_value(outputs).expect("serialization failed"))
 }
 
+/// Parses the input file given by the `input_file` option, if any.
+fn parse_input_file(test: &Test, parsed: &Compiler, handler: &Handler) -> Result<Option<InputAst>, ()> {
+    // ``` input_file: inputs/main.in ```
+    // The path is relative to the directory of the test.
+    let input_file = match test.config.extra.get("input_file") {
+        Some(input_file) => input_file.as_str().unwrap(),
+        None => return Ok(None),
+    };
+    let source = fs::read_to_string(test.path.parent().unwrap().join(input_file)).expect("failed to read input file");
+
+    // Name the input file by its relative path, so that expectations do not depend on where the tests are.
+    let input_sf = with_session_globals(|s| s.source_map.new_source(&source, FileName::Custom(input_file.into())));
+    handler
+        .extend_if_error(leo_parser::parse_input(handler, parsed.node_builder(), &input_sf.src, input_sf.start_pos))
+        .map(Some)
+}
+
+/// Checks `input_ast` against the transitions of `program`.
+fn check_input_file(program: &Program, input_ast: &InputAst, handler: &Handler) -> Result<(), ()> {
+    let structs: IndexMap<Symbol, Struct> =
+        program.program_scopes.values().flat_map(|program_scope| program_scope.structs.iter().cloned()).collect();
+    for (_, function) in program.program_scopes.values().flat_map(|program_scope| program_scope.functions.iter()) {
+        if function.variant == Variant::Transition
+            && input_ast.sections.iter().any(|section| section.name == function.identifier.name)
+        {
+            // The errors are emitted to the handler.
+            input_ast.program_inputs(function, None, structs.clone(), handler).map_err(|_| ())?;
+        }
+    }
+    Ok(())
+}
+
 struct TestRunner;
 
 impl Runner for TestRunner {
@@ -163,3 +215,33 @@ impl Runner for TestRunner {
 pub fn compiler_tests() {
     leo_test_framework::run_tests(&TestRunner, "compiler");
 }
+
+#[test]
+pub fn compile_from_string() {
+    create_session_if_not_set_then(|_| {
+        let handler = Handler::default();
+
+        // A well-formed program is compiled to bytecode, without a main file on disk.
+        let program = "
+program test.aleo {
+    transition main(a: u8, b: u8) -> u8 {
+        return a + b;
+    }
+}";
+        let mut compiler = new_compiler(&handler, "compile-from-string".into(), None);
+        let (_, bytecode) =
+            compiler.compile_from_string(program, FileName::Custom("compile-from-string".into())).unwrap();
+        assert!(bytecode.contains("program test.aleo;"));
+        assert!(bytecode.contains("add r0 r1 into r2;"));
+
+        // A program that does not type check is an error.
+        let program = "
+program test.aleo {
+    transition main(a: u8) -> u16 {
+        return a;
+    }
+}";
+        let mut compiler = new_compiler(&handler, "compile-from-string".into(), None);
+        assert!(compiler.compile_from_string(program, FileName::Custom("compile-from-string".into())).is_err());
+    })
+}
@@ -31,9 +31,11 @@ use utilities::{
     Network,
 };
 
+use leo_ast::Program;
 use leo_compiler::{CompilerOptions, OutputOptions};
 use leo_errors::emitter::Handler;
-use leo_span::symbol::create_session_if_not_set_then;
+use leo_passes::{format_value, parse_value, Interpreter};
+use leo_span::{symbol::create_session_if_not_set_then, Symbol};
 use leo_test_framework::{
     runner::{Namespace, ParseType, Runner},
     test::TestExpectationMode,
@@ -120,6 +122,9 @@ fn run_test(test: Test, handler: &Handler, err_buf: &BufferEmitter) -> Result<Va
         let mut parsed =
             handler.extend_if_error(parse_program(handler, &test.content, cwd.clone(), Some(compiler_options)))?;
 
+        // Keep the parsed program, so that the interpreter can evaluate it.
+        let program = parsed.ast.ast.clone();
+
         // Compile the program to bytecode.
         let program_name = format!("{}.{}", parsed.program_name, parsed.network);
         let bytecode = handler.extend_if_error(compile_and_process(&mut parsed))?;
@@ -190,6 +195,13 @@ fn run_test(test: Test, handler: &Handler, err_buf: &BufferEmitter) -> Result<Va
                     Err(err) => format!("SnarkVMError({err})"),
                 };
 
+                // Check that the interpreter agrees with snarkVM.
+                let input_strings: Vec<_> = inputs.iter().map(|input| input.to_string()).collect();
+                buffer_if_err(
+                    err_buf,
+                    check_interpreter(&program, &function_name.to_string(), &input_strings, &output_string),
+                )?;
+
                 // Store the inputs and outputs in a map.
                 let mut result = BTreeMap::new();
                 result.insert("input".to_string(), input_string);
@@ -232,6 +244,52 @@ fn run_test(test: Test, handler: &Handler, err_buf: &BufferEmitter) -> Result<Va
     Ok(serde_yaml::to_value(outputs).expect("serialization failed"))
 }
 
+/// Evaluates the function `function_name` with the interpreter, and checks that it agrees with the `output` of snarkVM.
+/// Cases that use constructs the interpreter does not support, or that finalize, are skipped.
+fn check_interpreter(program: &Program, function_name: &str, inputs: &[String], output: &str) -> Result<(), String> {
+    let name = Symbol::intern(function_name);
+    let Some(program_scope) = program.program_scopes.values().next() else { return Ok(()) };
+    let Some((_, function)) = program_scope.functions.iter().find(|(function, _)| *function == name) else {
+        return Ok(());
+    };
+    // The outputs of snarkVM include the future of the finalize block, which the interpreter does not evaluate.
+    if function.finalize.is_some() {
+        return Ok(());
+    }
+
+    let result = Interpreter::from_program_scope(program_scope).and_then(|mut interpreter| {
+        let arguments = inputs
+            .iter()
+            .zip(function.input.iter())
+            .map(|(input, parameter)| parse_value(input, &parameter.type_(), Default::default()))
+            .collect::<Result<Vec<_>, _>>()?;
+        interpreter.call(name, arguments)
+    });
+
+    let snarkvm_failed = output.starts_with("SnarkVMError");
+    match result {
+        Err(error) if Interpreter::is_unsupported(&error) => Ok(()),
+        Err(_) if snarkvm_failed => Ok(()),
+        Err(error) => Err(format!("The interpreter failed on `{function_name}` with {error}, but snarkVM output {output}.")),
+        Ok(value) if snarkvm_failed => {
+            Err(format!("The interpreter output {value} for `{function_name}`, but snarkVM failed with {output}."))
+        }
+        Ok(value) => {
+            let values = match value {
+                leo_ast::Value::Tuple(values) => values,
+                value => vec![value],
+            };
+            match values.iter().map(format_value).collect::<Option<Vec<_>>>() {
+                Some(outputs) if format!("[{}]", outputs.join(", ")) != output => Err(format!(
+                    "The interpreter output [{}] for `{function_name}`, but snarkVM output {output}.",
+                    outputs.join(", ")
+                )),
+                _ => Ok(()),
+            }
+        }
+    }
+}
+
 struct TestRunner;
 
 impl Runner for TestRunner {
@@ -0,0 +1,61 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_compiler::Compiler;
+use leo_errors::{
+    emitter::Handler,
+    LeoError,
+    explanations::{explain, message_codes},
+};
+use leo_span::{source_map::FileName, symbol::create_session_if_not_set_then};
+
+use std::path::PathBuf;
+
+/// Returns the program in the `leo` block of an explanation.
+fn example_program(example: &str) -> &str {
+    let program = example.split("```leo\n").nth(1).expect("the example has no `leo` block");
+    &program[..program.find("```").expect("the `leo` block is not closed")]
+}
+
+/// Returns the codes of the errors and warnings that compiling `program` reports.
+fn reported_codes(program: &str) -> Vec<String> {
+    create_session_if_not_set_then(|_| {
+        let (handler, buf) = Handler::new_with_buf();
+        let mut compiler = Compiler::new("test".into(), "aleo".into(), &handler, PathBuf::new(), PathBuf::new(), None);
+        let result = compiler.compile_from_string(program, FileName::Custom("compiler-test".into()));
+
+        let mut codes = buf.extract_errs().into_inner().iter().map(|error| error.error_code()).collect::<Vec<_>>();
+        // The error that stops compilation is returned rather than emitted, unless it refers to the last emitted one.
+        if let Err(error) = result {
+            if !matches!(error, LeoError::LastErrorCode(_)) {
+                codes.push(error.error_code());
+            }
+        }
+        codes.extend(buf.extract_warnings().into_inner().iter().map(|warning| warning.error_code()));
+        codes
+    })
+}
+
+#[test]
+fn examples_report_their_codes() {
+    for (code, _) in message_codes() {
+        let Some(example) = explain(&code).and_then(|explanation| explanation.example) else {
+            continue;
+        };
+        let codes = reported_codes(example_program(example));
+        assert!(codes.contains(&code), "the example of `{code}` reports {codes:?}");
+    }
+}
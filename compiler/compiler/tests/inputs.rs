@@ -0,0 +1,426 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{Function, InputBuilder, InputValue, NodeBuilder};
+use leo_compiler::Compiler;
+use leo_errors::emitter::Handler;
+use leo_passes::SymbolTable;
+use leo_span::{
+    source_map::FileName,
+    symbol::{create_session_if_not_set_then, with_session_globals},
+    Symbol,
+};
+
+use indexmap::IndexMap;
+use std::path::PathBuf;
+
+const PROGRAM: &str = "
+program test.aleo {
+    transition main(public a: u8, b: u32) -> u32 {
+        return a as u32 + b;
+    }
+}";
+
+const JSON_PROGRAM: &str = "
+program test.aleo {
+    struct Point {
+        x: u8,
+        y: u8,
+    }
+
+    transition main(public a: u8, b: [u32; 2], p: Point, f: field) -> u8 {
+        return a;
+    }
+}";
+
+/// Compiles `program`, returning its functions and symbol table.
+fn compile(program: &str, handler: &Handler) -> (IndexMap<Symbol, Function>, SymbolTable) {
+    let mut compiler = Compiler::new("test".into(), "aleo".into(), handler, PathBuf::new(), PathBuf::new(), None);
+    let (symbol_table, _) = compiler.compile_from_string(program, FileName::Custom("compiler-test".into())).unwrap();
+    let functions =
+        compiler.ast.ast.program_scopes.into_values().flat_map(|program_scope| program_scope.functions).collect();
+    (functions, symbol_table)
+}
+
+/// Returns the values that the `[main]` section of `input` provides to the `main` transition of `program`,
+/// or the codes of the errors that are reported for it.
+fn main_inputs(program: &str, input: &str) -> Result<Vec<String>, Vec<String>> {
+    create_session_if_not_set_then(|_| {
+        let (handler, buf) = Handler::new_with_buf();
+        let (functions, symbol_table) = compile(program, &handler);
+        let function = &functions[&Symbol::intern("main")];
+
+        let input_sf = with_session_globals(|s| s.source_map.new_source(input, FileName::Custom("input-test".into())));
+        let input_ast =
+            leo_parser::parse_input(&handler, &NodeBuilder::default(), &input_sf.src, input_sf.start_pos).unwrap();
+
+        input_ast
+            .program_inputs(function, None, symbol_table.structs, &handler)
+            .map_err(|_| buf.extract_errs().into_inner().iter().map(|error| error.error_code()).collect())
+    })
+}
+
+/// Returns the values that the `[main]` section of the input file `main.in` provides to the `main` transition of
+/// `program`, where `files` are the names and contents of the input files in its directory,
+/// or the codes of the errors that are reported for it.
+fn included_main_inputs(program: &str, files: &[(&str, &str)]) -> Result<Vec<String>, Vec<String>> {
+    create_session_if_not_set_then(|_| {
+        let (handler, buf) = Handler::new_with_buf();
+        let (functions, symbol_table) = compile(program, &handler);
+        let function = &functions[&Symbol::intern("main")];
+
+        let directory = tempfile::tempdir().unwrap();
+        for (name, contents) in files {
+            std::fs::write(directory.path().join(name), contents).unwrap();
+        }
+        let input_ast =
+            leo_parser::parse_input_file(&handler, &NodeBuilder::default(), &directory.path().join("main.in"))
+                .map_err(|error| vec![error.error_code()])?;
+
+        input_ast
+            .program_inputs(function, None, symbol_table.structs, &handler)
+            .map_err(|_| buf.extract_errs().into_inner().iter().map(|error| error.error_code()).collect())
+    })
+}
+
+/// Returns the values that the `main` entry of the JSON input `json` provides to the `main` transition of `program`,
+/// or the message of the error that is reported for it.
+fn json_main_inputs(program: &str, json: &str) -> Result<Vec<String>, String> {
+    create_session_if_not_set_then(|_| {
+        let (handler, _) = Handler::new_with_buf();
+        let (functions, symbol_table) = compile(program, &handler);
+        let function = &functions[&Symbol::intern("main")];
+
+        let json = serde_json::from_str(json).unwrap();
+        let input_ast = leo_ast::json_input_to_ast(&json, &functions, &symbol_table.structs, &NodeBuilder::default())
+            .map_err(|error| error.to_string())?;
+
+        input_ast.program_inputs(function, None, symbol_table.structs, &handler).map_err(|error| error.to_string())
+    })
+}
+
+#[test]
+fn inputs_in_order() {
+    let input = "
+[main]
+public a: u8 = 1u8;
+b: u32 = 2u32;
+";
+    assert_eq!(main_inputs(PROGRAM, input), Ok(vec!["1u8".to_string(), "2u32".to_string()]));
+}
+
+#[test]
+fn inputs_out_of_order() {
+    // The values are matched to the inputs by name, and are passed in the order of the inputs.
+    let input = "
+[main]
+b: u32 = 2u32;
+public a: u8 = 1u8;
+";
+    assert_eq!(main_inputs(PROGRAM, input), Ok(vec!["1u8".to_string(), "2u32".to_string()]));
+}
+
+#[test]
+fn inputs_missing() {
+    let input = "
+[main]
+public a: u8 = 1u8;
+";
+    assert_eq!(main_inputs(PROGRAM, input), Err(vec!["EINP0371004".to_string()]));
+}
+
+#[test]
+fn inputs_extra() {
+    // A value for an input that `main` does not have.
+    let input = "
+[main]
+public a: u8 = 1u8;
+b: u32 = 2u32;
+c: u32 = 3u32;
+";
+    assert_eq!(main_inputs(PROGRAM, input), Err(vec!["EINP0371003".to_string()]));
+
+    // A second value for the same input.
+    let input = "
+[main]
+public a: u8 = 1u8;
+b: u32 = 2u32;
+b: u32 = 3u32;
+";
+    assert_eq!(main_inputs(PROGRAM, input), Err(vec!["EINP0371015".to_string()]));
+}
+
+#[test]
+fn inputs_mistyped() {
+    let input = "
+[main]
+public a: u8 = 1u8;
+b: u8 = 2u8;
+";
+    assert_eq!(main_inputs(PROGRAM, input), Err(vec!["EINP0371005".to_string()]));
+}
+
+#[test]
+fn included_inputs() {
+    // `common.in` is included by both `a.in` and `b.in`, but its section is only added once.
+    let files = [
+        ("main.in", "include \"a.in\";\ninclude \"b.in\";\n[main]\nb: u32 = 2u32;\n"),
+        ("a.in", "include \"common.in\";\n"),
+        ("b.in", "include \"common.in\";\n"),
+        ("common.in", "[main]\npublic a: u8 = 1u8;\n"),
+    ];
+    assert_eq!(included_main_inputs(PROGRAM, &files), Ok(vec!["1u8".to_string(), "2u32".to_string()]));
+}
+
+#[test]
+fn included_inputs_not_found() {
+    let files = [("main.in", "include \"missing.in\";\n[main]\npublic a: u8 = 1u8;\nb: u32 = 2u32;\n")];
+    assert_eq!(included_main_inputs(PROGRAM, &files), Err(vec!["EINP0371013".to_string()]));
+}
+
+#[test]
+fn included_inputs_cycle() {
+    let files = [
+        ("main.in", "include \"a.in\";\n[main]\npublic a: u8 = 1u8;\nb: u32 = 2u32;\n"),
+        ("a.in", "include \"b.in\";\n"),
+        ("b.in", "include \"a.in\";\n"),
+    ];
+    assert_eq!(included_main_inputs(PROGRAM, &files), Err(vec!["EINP0371014".to_string()]));
+
+    // A file that includes itself.
+    let files = [("main.in", "include \"main.in\";\n[main]\npublic a: u8 = 1u8;\nb: u32 = 2u32;\n")];
+    assert_eq!(included_main_inputs(PROGRAM, &files), Err(vec!["EINP0371014".to_string()]));
+}
+
+#[test]
+fn json_inputs_valid() {
+    // Values are given as JSON values or as Leo literals, and are passed in the order of the inputs.
+    let json = r#"{ "main": { "f": "1field", "p": { "y": "2u8", "x": 1 }, "b": [1, "2u32"], "a": 3 } }"#;
+    assert_eq!(
+        json_main_inputs(JSON_PROGRAM, json),
+        Ok(vec!["3u8".to_string(), "[1u32,2u32]".to_string(), "{y: 2u8, x: 1u8}".to_string(), "1field".to_string()])
+    );
+}
+
+#[test]
+fn json_inputs_mistyped() {
+    let error = |json| json_main_inputs(JSON_PROGRAM, json).unwrap_err();
+
+    let json = r#"{ "main": { "a": "3u32", "b": [1, 2], "p": { "x": 1, "y": 2 }, "f": 1 } }"#;
+    assert!(error(json).contains("at `main.a`, expected a value of type `u8`, found `\"3u32\"`"));
+
+    let json = r#"{ "main": { "a": 3, "b": [1, true], "p": { "x": 1, "y": 2 }, "f": 1 } }"#;
+    assert!(error(json).contains("at `main.b[1]`, expected a value of type `u32`, found `true`"));
+
+    let json = r#"{ "main": { "a": 3, "b": [1, 2], "p": { "x": 1, "y": 2.5 }, "f": 1 } }"#;
+    assert!(error(json).contains("at `main.p.y`, expected a value of type `u8`, found `2.5`"));
+
+    // Strings must hold a literal, so they cannot smuggle in other syntax.
+    let json = r#"{ "main": { "a": "3u8; b: u8 = 1u8", "b": [1, 2], "p": { "x": 1, "y": 2 }, "f": 1 } }"#;
+    assert!(error(json).contains("at `main.a`, expected a value of type `u8`"));
+}
+
+#[test]
+fn json_inputs_malformed() {
+    let error = |json| json_main_inputs(JSON_PROGRAM, json).unwrap_err();
+
+    assert!(error(r#"[1, 2]"#).contains("expected an object of sections"));
+    assert!(error(r#"{ "main": 1 }"#).contains("at `main`, expected an object of inputs"));
+    assert!(error(r#"{ "mian": {} }"#).contains("at `mian`, there is no function named `mian`"));
+    assert!(error(r#"{ "main.": {} }"#).contains(r#"at `["main."]`, expected a section named"#));
+    assert!(
+        error(r#"{ "main": { "c": 1 } }"#).contains("at `main.c`, function `main` does not have an input named `c`")
+    );
+
+    let json = r#"{ "main": { "a": 3, "b": [1], "p": { "x": 1, "y": 2 }, "f": 1 } }"#;
+    assert!(error(json).contains("at `main.b`, expected an array of 2 elements, found 1"));
+
+    let json = r#"{ "main": { "a": 3, "b": [1, 2], "p": { "x": 1 }, "f": 1 } }"#;
+    assert!(error(json).contains("at `main.p`, missing member `y`"));
+
+    let json = r#"{ "main": { "a": 3, "b": [1, 2], "p": { "x": 1, "y": 2, "z": 3 }, "f": 1 } }"#;
+    assert!(error(json).contains("at `main.p.z`, struct `Point` does not have a member named `z`"));
+}
+
+const SKELETON_PROGRAM: &str = "
+program test.aleo {
+    struct Point {
+        x: u8,
+        y: field,
+    }
+
+    record Token {
+        owner: address,
+        amount: u64,
+    }
+
+    transition main(public a: u8, b: [bool; 2], p: Point, t: Token) -> u8 {
+        return a;
+    }
+
+    transition verify(s: signature, public a: address) -> bool {
+        return true;
+    }
+
+    function helper(a: u8) -> u8 {
+        return a;
+    }
+}";
+
+/// Returns the input file skeleton of `program`.
+fn skeleton(program: &str) -> String {
+    create_session_if_not_set_then(|_| {
+        let (handler, _) = Handler::new_with_buf();
+        let (functions, symbol_table) = compile(program, &handler);
+        leo_ast::input_file_skeleton(&functions, &symbol_table.structs)
+    })
+}
+
+#[test]
+fn skeleton_zero_values() {
+    // Only transitions get a section, and inputs without a zero value are commented out.
+    let expected = "\
+[main]
+public a: u8 = 0u8;
+b: [bool; 2] = [false; 2];
+p: Point = { x: 0u8, y: 0field };
+t: Token = { owner: aleo1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq3ljyzc, amount: 0u64, _nonce: 0group };
+
+[verify]
+// s: signature = ?;
+public a: address = aleo1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq3ljyzc;
+";
+    assert_eq!(skeleton(SKELETON_PROGRAM), expected);
+}
+
+#[test]
+fn skeleton_round_trip() {
+    // The skeleton is a valid input file for the program.
+    assert!(main_inputs(SKELETON_PROGRAM, &skeleton(SKELETON_PROGRAM)).is_ok());
+}
+
+const BUILDER_PROGRAM: &str = "
+program test.aleo {
+    struct Point {
+        x: u8,
+        y: u8,
+    }
+
+    record Token {
+        owner: address,
+        amount: u64,
+    }
+
+    transition main(
+        public a: u8,
+        b: [u32; 2],
+        f: field,
+        g: group,
+        r: address,
+        c: bool,
+        s: scalar,
+        p: Point,
+        t: Token,
+    ) -> u8 {
+        return a;
+    }
+}";
+
+const RECORD: &str = "{ owner: aleo1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq3ljyzc.private, \
+    amount: 5u64.private, _nonce: 0group.public }";
+
+const ADDRESS: &str = "aleo1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq3ljyzc";
+
+/// Returns the values that `builder` gives to the `main` transition of `program`, or the message of its error.
+fn build_main_inputs(program: &str, builder: InputBuilder) -> Result<Vec<String>, String> {
+    create_session_if_not_set_then(|_| {
+        let (handler, _) = Handler::new_with_buf();
+        let (functions, _) = compile(program, &handler);
+        builder.build(&functions[&Symbol::intern("main")]).map_err(|error| error.to_string())
+    })
+}
+
+#[test]
+fn builder_inputs() {
+    // The values are passed in the order of the inputs, whatever order they are set in.
+    let builder = InputBuilder::new()
+        .input("c", true)
+        .address("r", ADDRESS)
+        .group("g", 0)
+        .field_element("f", "5")
+        .array("b", [1u32, 2u32])
+        .record("t", RECORD)
+        .struct_("p", [("x", 1u8), ("y", 2u8)])
+        .scalar("s", 7)
+        .input("a", 3u8);
+    assert_eq!(
+        build_main_inputs(BUILDER_PROGRAM, builder),
+        Ok(vec![
+            "3u8".to_string(),
+            "[1u32, 2u32]".to_string(),
+            "5field".to_string(),
+            "0group".to_string(),
+            ADDRESS.to_string(),
+            "true".to_string(),
+            "7scalar".to_string(),
+            "{ x: 1u8, y: 2u8 }".to_string(),
+            RECORD.to_string(),
+        ])
+    );
+}
+
+#[test]
+fn builder_inputs_invalid() {
+    let error = |builder| build_main_inputs(BUILDER_PROGRAM, builder).unwrap_err();
+    let builder = || {
+        InputBuilder::new()
+            .input("a", 3u8)
+            .array("b", [1u32, 2u32])
+            .field_element("f", 5)
+            .group("g", 0)
+            .address("r", ADDRESS)
+            .input("c", true)
+            .scalar("s", 7)
+            .struct_("p", [("x", 1u8), ("y", 2u8)])
+            .record("t", RECORD)
+    };
+
+    assert!(error(InputBuilder::new()).contains("missing a value for input `a` of function `main`"));
+    assert!(error(builder().input("d", 1u8)).contains("function `main` does not have an input named `d`"));
+    assert!(error(builder().input("a", 3u16)).contains("input `a` has type `u8`, but the value `3u16` does not"));
+    assert!(
+        error(builder().array("b", [1u32])).contains("input `b` has type `[u32; 2]`, but the value `[1u32]` does not")
+    );
+    assert!(error(builder().field_element("f", "0x5")).contains("input `f` has type `field`"));
+    assert!(error(builder().group("g", -1)).contains("input `g` has type `group`"));
+    assert!(error(builder().address("r", "aleo")).contains("input `r` has type `address`"));
+    assert!(error(builder().scalar("s", "1.5")).contains("input `s` has type `scalar`"));
+    assert!(error(builder().input("p", 1u8)).contains("input `p` has type `Point`"));
+}
+
+#[test]
+fn input_value_display() {
+    // Values are displayed as snarkVM literals, with the suffix of their type.
+    assert_eq!(InputValue::from(true).to_string(), "true");
+    assert_eq!(InputValue::from(5u64).to_string(), "5u64");
+    assert_eq!(InputValue::from(-5i8).to_string(), "-5i8");
+    assert_eq!(InputValue::Field("5".to_string()).to_string(), "5field");
+    assert_eq!(InputValue::Address(ADDRESS.to_string()).to_string(), ADDRESS);
+    assert_eq!(InputValue::from(vec![vec![1u8, 2u8], vec![3u8, 4u8]]).to_string(), "[[1u8, 2u8], [3u8, 4u8]]");
+    assert_eq!(InputValue::Scalar("5".to_string()).to_string(), "5scalar");
+    let members = [("x".to_string(), InputValue::from(1u8)), ("y".to_string(), InputValue::from(vec![2u8]))];
+    assert_eq!(InputValue::Struct(members.into_iter().collect()).to_string(), "{ x: 1u8, y: [2u8] }");
+}
@@ -274,8 +274,16 @@ impl<'a> ProgramVisitor<'a> for CheckUniqueNodeIds<'a> {
     fn visit_function(&mut self, input: &'a Function) {
         let Function { annotations, identifier, input, output, block, finalize, id, .. } = input;
         // Check the annotations.
-        for Annotation { identifier, id, .. } in annotations {
+        for Annotation { identifier, arguments, id, .. } in annotations {
             self.visit_identifier(identifier, &Default::default());
+            for argument in arguments {
+                match argument {
+                    AnnotationArgument::Identifier(identifier) => {
+                        self.visit_identifier(identifier, &Default::default())
+                    }
+                    AnnotationArgument::Integer(_, _, id) => self.check(*id),
+                }
+            }
             self.check(*id);
         }
         // Check the function name.
@@ -0,0 +1,93 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The formatter to print a Leo program in its canonical form.
+//!
+//! This module contains the [`format_source()`] method, which parses a program along with its comments
+//! and prints it back with canonical indentation, spacing, and line breaks.
+//! Comments and single blank lines between statements and items are kept.
+
+mod printer;
+use printer::Printer;
+
+use crate::{parse_with_comments, Comment};
+
+use leo_ast::{Ast, NodeBuilder, Program};
+use leo_errors::{emitter::Handler, ParserError, Result};
+use leo_span::{source_map::FileName, span::BytePos, symbol::with_session_globals};
+
+use serde_json::Value;
+
+/// Formats the Leo program in `source`, which starts at `start_pos` in the source map.
+///
+/// The formatted program is parsed again, to guarantee that formatting preserves the program and its comments,
+/// and that formatting the result once more leaves it unchanged.
+pub fn format_source(handler: &Handler, source: &str, start_pos: BytePos) -> Result<String> {
+    let (formatted, program, comments) = format_once(handler, source, start_pos)?;
+
+    // Warnings of the formatted program were already reported for the original.
+    let (handler, _) = Handler::new_with_buf();
+    let formatted_file =
+        with_session_globals(|s| s.source_map.new_source(&formatted, FileName::Custom("formatted".into())));
+    let (reformatted, formatted_program, formatted_comments) =
+        format_once(&handler, &formatted_file.src, formatted_file.start_pos)
+            .map_err(|_| ParserError::formatter_failed("does not parse"))?;
+
+    if without_positions(program)? != without_positions(formatted_program)? {
+        return Err(ParserError::formatter_failed("differs from the original program").into());
+    }
+    if contents(&comments) != contents(&formatted_comments) {
+        return Err(ParserError::formatter_failed("does not keep the comments of the original program").into());
+    }
+    if reformatted != formatted {
+        return Err(ParserError::formatter_failed("changes when it is formatted again").into());
+    }
+
+    Ok(formatted)
+}
+
+/// Parses and formats the program in `source`, returning the formatted program along with the parsed one.
+fn format_once(handler: &Handler, source: &str, start_pos: BytePos) -> Result<(String, Program, Vec<Comment>)> {
+    let (program, comments) = parse_with_comments(handler, &NodeBuilder::default(), source, start_pos)?;
+    let formatted = Printer::new(source, start_pos, comments.clone()).print_program(&program);
+    Ok((formatted, program, comments))
+}
+
+/// Returns the JSON of the program without its spans and node IDs, which formatting is free to change.
+fn without_positions(program: Program) -> Result<Value> {
+    Ok(strip_positions(Ast::new(program).to_json_value()?))
+}
+
+/// Removes the spans and node IDs from the JSON, including those in the (possibly nested) strings
+/// that identifiers serialize to.
+fn strip_positions(value: Value) -> Value {
+    match value {
+        Value::String(string) => match serde_json::from_str(&string) {
+            Ok(value @ (Value::Object(_) | Value::String(_))) => strip_positions(value),
+            _ => Value::String(string),
+        },
+        Value::Object(map) => Value::Object(
+            map.into_iter().filter(|(key, _)| key != "span" && key != "id").map(|(k, v)| (k, strip_positions(v))).collect(),
+        ),
+        Value::Array(values) => Value::Array(values.into_iter().map(strip_positions).collect()),
+        value => value,
+    }
+}
+
+/// Returns the text of the comments, ignoring the line breaks that end line comments.
+fn contents(comments: &[Comment]) -> Vec<&str> {
+    comments.iter().map(|comment| comment.content.trim_end()).collect()
+}
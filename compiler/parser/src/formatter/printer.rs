@@ -0,0 +1,716 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Comment;
+
+use leo_ast::*;
+use leo_span::span::{BytePos, Pos, Span};
+
+use std::collections::VecDeque;
+
+/// The width of a line, beyond which struct initializers are split across lines.
+const MAX_WIDTH: usize = 100;
+
+/// The indentation of each nested scope.
+const INDENT: &str = "    ";
+
+/// The precedence of casts, e.g. `a as u8`.
+const CAST: u8 = 12;
+/// The precedence of prefix operators and negative literals, e.g. `!a` or `-1i8`.
+const PREFIX: u8 = 13;
+/// The precedence of accesses, calls, method calls, and atoms, e.g. `a.b`, `f(a)`, or `a.add_wrapped(b)`.
+const POSTFIX: u8 = 14;
+
+/// Prints a Leo program in its canonical form, interleaving the comments of its source.
+pub(super) struct Printer<'a> {
+    /// The source code of the program.
+    source: &'a str,
+    /// The position of the source code in the source map.
+    start_pos: BytePos,
+    /// The comments that have not been printed yet, in source order.
+    comments: VecDeque<Comment>,
+    /// The end of the last node or comment that was printed.
+    last: BytePos,
+    /// The width of a line, beyond which struct initializers are split across lines.
+    max_width: usize,
+    /// The formatted program.
+    out: String,
+    /// The current level of indentation.
+    indent: usize,
+    /// Whether nothing has been written on the current line, so that indentation is due.
+    at_line_start: bool,
+    /// Whether the next element is the first in its scope, and so is never preceded by a blank line.
+    at_scope_start: bool,
+    /// Whether struct initializers need parentheses, as in the condition of an `if` statement.
+    disallow_struct_construction: bool,
+}
+
+impl<'a> Printer<'a> {
+    /// Returns a new printer for the program in `source`, with the comments found in it.
+    pub(super) fn new(source: &'a str, start_pos: BytePos, comments: Vec<Comment>) -> Self {
+        Self {
+            source,
+            start_pos,
+            comments: comments.into(),
+            last: start_pos,
+            max_width: MAX_WIDTH,
+            out: String::new(),
+            indent: 0,
+            at_line_start: true,
+            at_scope_start: true,
+            disallow_struct_construction: false,
+        }
+    }
+
+    /// Prints the program, returning the formatted source code.
+    pub(super) fn print_program(mut self, program: &Program) -> String {
+        for (name, (_, span)) in &program.imports {
+            self.begin(span.lo);
+            self.write(&format!("import {name}.leo;"));
+            self.end(span.hi);
+        }
+        for program_scope in program.program_scopes.values() {
+            self.begin(program_scope.span.lo);
+            self.program_scope(program_scope);
+            self.end(program_scope.span.hi);
+        }
+        // Print the comments after the last item.
+        self.leading_comments(self.end_pos());
+        self.out
+    }
+
+    /// Prints a program scope, with its items in source order.
+    fn program_scope(&mut self, program_scope: &ProgramScope) {
+        self.write(&format!("program {} ", program_scope.program_id));
+        self.open(self.find_open_brace(program_scope.program_id.network.span.hi));
+
+        let mut items = Vec::new();
+        items.extend(program_scope.consts.iter().map(|(_, declaration)| Item::Const(declaration)));
+        items.extend(program_scope.structs.iter().map(|(_, struct_)| Item::Struct(struct_)));
+        items.extend(program_scope.mappings.iter().map(|(_, mapping)| Item::Mapping(mapping)));
+        items.extend(program_scope.functions.iter().map(|(_, function)| Item::Function(function)));
+        items.sort_by_key(|item| item.lo());
+
+        for item in items {
+            match item {
+                Item::Const(declaration) => {
+                    self.begin(declaration.span.lo);
+                    self.const_declaration(declaration);
+                    self.end(declaration.span.hi);
+                }
+                Item::Struct(struct_) => {
+                    self.begin(struct_.span.lo);
+                    self.struct_(struct_);
+                    self.end(struct_.span.hi);
+                }
+                Item::Mapping(mapping) => {
+                    self.begin(mapping.span.lo);
+                    self.write(&format!(
+                        "mapping {}: {} => {};",
+                        mapping.identifier,
+                        type_(&mapping.key_type),
+                        type_(&mapping.value_type)
+                    ));
+                    self.end(mapping.span.hi);
+                }
+                Item::Function(function) => self.function(function),
+            }
+        }
+
+        self.close(program_scope.span.hi);
+    }
+
+    /// Prints a struct or record definition.
+    fn struct_(&mut self, struct_: &Struct) {
+        let keyword = if struct_.is_record { "record" } else { "struct" };
+        self.write(&format!("{keyword} {} ", struct_.identifier));
+        if struct_.members.is_empty() && !self.has_comments_within(struct_.span) {
+            return self.write("{}");
+        }
+
+        self.open(self.find_open_brace(struct_.identifier.span.hi));
+        for member in &struct_.members {
+            self.begin(member.span.lo);
+            self.write(&format!("{}{}: {},", mode(member.mode), member.identifier, type_(&member.type_)));
+            self.end(member.span.hi);
+        }
+        self.close(struct_.span.hi);
+    }
+
+    /// Prints a function, along with its annotations and `finalize` block.
+    fn function(&mut self, function: &Function) {
+        for annotation in &function.annotations {
+            self.begin(annotation.span.lo);
+            self.write(&annotation.to_string());
+            self.end(annotation.span.hi);
+        }
+        match function.annotations.is_empty() {
+            true => self.begin(function.span.lo),
+            false => self.leading_comments(function.span.lo),
+        }
+        let variant = match function.variant {
+            Variant::Inline => "inline",
+            Variant::Standard => "function",
+            Variant::Transition => "transition",
+        };
+        self.write(&format!("{variant} {}", function.identifier));
+        self.signature(&function.input, &function.output);
+        self.block(&function.block);
+        self.end(function.block.span.hi);
+
+        if let Some(finalize) = &function.finalize {
+            self.begin(finalize.span.lo);
+            self.write(&format!("finalize {}", finalize.identifier));
+            self.signature(&finalize.input, &finalize.output);
+            self.block(&finalize.block);
+            self.end(finalize.span.hi);
+        }
+    }
+
+    /// Prints the inputs and outputs of a function, followed by a space.
+    fn signature(&mut self, inputs: &[Input], outputs: &[Output]) {
+        let inputs = inputs
+            .iter()
+            .map(|input| match input {
+                Input::Internal(input) => format!("{}{}: {}", mode(input.mode), input.identifier, type_(&input.type_)),
+                Input::External(input) => format!("{}: {}", input.identifier, external_record(input)),
+            })
+            .collect::<Vec<_>>();
+        self.write(&format!("({}) ", inputs.join(", ")));
+
+        let outputs = outputs
+            .iter()
+            .map(|output| match output {
+                Output::Internal(output) => format!("{}{}", mode(output.mode), type_(&output.type_)),
+                Output::External(output) => external_record(output),
+            })
+            .collect::<Vec<_>>();
+        match outputs.len() {
+            0 => {}
+            1 => self.write(&format!("-> {} ", outputs[0])),
+            _ => self.write(&format!("-> ({}) ", outputs.join(", "))),
+        }
+    }
+
+    /// Prints a block of statements.
+    fn block(&mut self, block: &Block) {
+        if block.statements.is_empty() && !self.has_comments_within(block.span) {
+            return self.write("{}");
+        }
+
+        self.open(block.span.lo);
+        for statement in &block.statements {
+            self.begin(statement.span().lo);
+            self.statement(statement);
+            self.end(statement.span().hi);
+        }
+        self.close(block.span.hi);
+    }
+
+    /// Prints a statement, without the comments around it.
+    fn statement(&mut self, statement: &Statement) {
+        match statement {
+            Statement::Assert(assert) => match &assert.variant {
+                AssertVariant::Assert(expression) => self.call_like("assert", [expression]),
+                AssertVariant::AssertEq(left, right) => self.call_like("assert_eq", [left, right]),
+                AssertVariant::AssertNeq(left, right) => self.call_like("assert_neq", [left, right]),
+            },
+            Statement::Assign(assign) => {
+                self.expression(&assign.place);
+                match &assign.value {
+                    // The parser desugars `a += b` into `a = a + b`, spanning the whole statement.
+                    Expression::Binary(binary) if binary.span == assign.span && is_operator(binary) => {
+                        self.write(&format!(" {}= ", binary.op));
+                        self.expression(&binary.right);
+                    }
+                    value => {
+                        self.write(" = ");
+                        self.expression(value);
+                    }
+                }
+            }
+            Statement::Block(block) => self.block(block),
+            Statement::Conditional(conditional) => self.conditional(conditional),
+            Statement::Console(console) => match &console.function {
+                ConsoleFunction::Assert(expression) => self.call_like("console.assert", [expression]),
+                ConsoleFunction::AssertEq(left, right) => self.call_like("console.assert_eq", [left, right]),
+                ConsoleFunction::AssertNeq(left, right) => self.call_like("console.assert_neq", [left, right]),
+            },
+            Statement::Const(declaration) => {
+                self.const_declaration(declaration);
+                return;
+            }
+            Statement::Definition(definition) => {
+                self.write("let ");
+                self.expression(&definition.place);
+                self.write(&format!(": {} = ", type_(&definition.type_)));
+                self.expression(&definition.value);
+            }
+            Statement::Expression(statement) => self.expression(&statement.expression),
+            Statement::Iteration(iteration) => {
+                self.write(&format!("for {}: {} in ", iteration.variable, type_(&iteration.type_)));
+                self.expression(&iteration.start);
+                self.write("..");
+                self.condition(&iteration.stop);
+                self.write(" ");
+                self.block(&iteration.block);
+                return;
+            }
+            Statement::Return(return_) => {
+                self.write("return");
+                if !matches!(return_.expression, Expression::Unit(_)) {
+                    self.write(" ");
+                    self.expression(&return_.expression);
+                }
+                match return_.finalize_arguments.as_deref() {
+                    None => {}
+                    Some([]) => self.write(" then finalize"),
+                    Some(arguments) => {
+                        self.write(" then finalize");
+                        self.arguments(arguments);
+                    }
+                }
+            }
+        }
+        if !matches!(statement, Statement::Block(_) | Statement::Conditional(_)) {
+            self.write(";");
+        }
+    }
+
+    /// Prints a `const` declaration.
+    fn const_declaration(&mut self, declaration: &ConstDeclaration) {
+        self.write(&format!("const {}: {} = ", declaration.place, type_(&declaration.type_)));
+        self.expression(&declaration.value);
+        self.write(";");
+    }
+
+    /// Prints an `if` statement, along with its `else` branches.
+    fn conditional(&mut self, conditional: &ConditionalStatement) {
+        self.write("if ");
+        self.condition(&conditional.condition);
+        self.write(" ");
+        self.block(&conditional.then);
+        match conditional.otherwise.as_deref() {
+            None => {}
+            Some(Statement::Conditional(otherwise)) => {
+                self.write(" else ");
+                self.conditional(otherwise);
+            }
+            Some(otherwise) => {
+                self.write(" else ");
+                self.statement(otherwise);
+            }
+        }
+    }
+
+    /// Prints a call-like statement, e.g. `assert_eq(a, b)`.
+    fn call_like<'e>(&mut self, name: &str, arguments: impl IntoIterator<Item = &'e Expression>) {
+        self.write(name);
+        self.arguments(arguments);
+    }
+
+    /// Prints a parenthesized list of arguments.
+    fn arguments<'e>(&mut self, arguments: impl IntoIterator<Item = &'e Expression>) {
+        self.write("(");
+        for (i, argument) in arguments.into_iter().enumerate() {
+            if i > 0 {
+                self.write(", ");
+            }
+            self.expression(argument);
+        }
+        self.write(")");
+    }
+
+    /// Prints an expression in a position where struct initializers need parentheses.
+    fn condition(&mut self, expression: &Expression) {
+        self.disallow_struct_construction = true;
+        self.operand(expression, 0);
+        self.disallow_struct_construction = false;
+    }
+
+    /// Prints an expression in a position that accepts any expression, e.g. an argument of a call.
+    fn expression(&mut self, expression: &Expression) {
+        let disallow_struct_construction = std::mem::take(&mut self.disallow_struct_construction);
+        self.operand(expression, 0);
+        self.disallow_struct_construction = disallow_struct_construction;
+    }
+
+    /// Prints an operand, which needs parentheses if its precedence is lower than `precedence`.
+    fn operand(&mut self, expression: &Expression, precedence: u8) {
+        let is_struct = matches!(expression, Expression::Struct(_));
+        if precedence_of(expression) < precedence || (is_struct && self.disallow_struct_construction) {
+            self.write("(");
+            self.expression(expression);
+            self.write(")");
+            return;
+        }
+
+        match expression {
+            Expression::Access(access) => self.access(access),
+            Expression::Array(array) => {
+                self.write("[");
+                for (i, element) in array.elements.iter().enumerate() {
+                    if i > 0 {
+                        self.write(", ");
+                    }
+                    self.expression(element);
+                }
+                self.write("]");
+            }
+            Expression::Binary(binary) => match (binary_precedence(binary.op), is_operator(binary)) {
+                (Some(precedence), true) => {
+                    // Comparisons do not associate, while the other operators associate to the left.
+                    let left = match precedence {
+                        3 | 4 => precedence + 1,
+                        _ => precedence,
+                    };
+                    self.operand(&binary.left, left);
+                    self.write(&format!(" {} ", binary.op));
+                    self.operand(&binary.right, precedence + 1);
+                }
+                _ => {
+                    self.operand(&binary.left, POSTFIX);
+                    // Note that only `&&` and `||` have no method, and those are always operators.
+                    self.write(&format!(".{}", binary.op.method_name().unwrap_or_default()));
+                    self.arguments([binary.right.as_ref()]);
+                }
+            },
+            Expression::Call(call) => {
+                if let Some(external) = &call.external {
+                    self.operand(external, POSTFIX);
+                    self.write(".leo/");
+                }
+                self.operand(&call.function, POSTFIX);
+                self.arguments(&call.arguments);
+            }
+            Expression::Cast(cast) => {
+                self.operand(&cast.expression, PREFIX);
+                self.write(&format!(" as {}", type_(&cast.type_)));
+            }
+            Expression::Struct(struct_) => self.struct_expression(struct_),
+            Expression::Err(err) => self.write(&err.to_string()),
+            Expression::Identifier(identifier) => self.write(&identifier.to_string()),
+            Expression::Literal(Literal::Group(group)) => match group.as_ref() {
+                GroupLiteral::Single(..) => self.write(&format!("{group}group")),
+                GroupLiteral::Tuple(tuple) => self.write(&format!("({}, {})group", tuple.x, tuple.y)),
+            },
+            Expression::Literal(literal) => self.write(&literal.to_string()),
+            Expression::Ternary(ternary) => {
+                self.operand(&ternary.condition, 1);
+                self.write(" ? ");
+                self.expression(&ternary.if_true);
+                self.write(" : ");
+                self.expression(&ternary.if_false);
+            }
+            Expression::Tuple(tuple) => self.arguments(&tuple.elements),
+            Expression::Unary(unary) if unary.span.lo < unary.receiver.span().lo => {
+                self.write(if let UnaryOperation::Not = unary.op { "!" } else { "-" });
+                self.operand(&unary.receiver, PREFIX);
+            }
+            Expression::Unary(unary) => {
+                self.operand(&unary.receiver, POSTFIX);
+                self.write(&format!(".{}()", unary.op.as_str()));
+            }
+            Expression::Unit(_) => self.write("()"),
+        }
+    }
+
+    /// Prints an access expression.
+    fn access(&mut self, access: &AccessExpression) {
+        match access {
+            AccessExpression::Array(access) => {
+                self.operand(&access.array, POSTFIX);
+                self.write("[");
+                self.expression(&access.index);
+                self.write("]");
+            }
+            AccessExpression::AssociatedConstant(constant) => {
+                self.write(&format!("{}::{}", type_(&constant.ty), constant.name));
+            }
+            AccessExpression::AssociatedFunction(function) => match function.arguments.split_first() {
+                // The parser turns method calls like `m.get(k)` into associated functions whose type has no span.
+                Some((receiver, arguments)) if matches!(&function.ty, Type::Identifier(ty) if ty.span == Span::default()) =>
+                {
+                    self.operand(receiver, POSTFIX);
+                    self.write(&format!(".{}", function.name));
+                    self.arguments(arguments);
+                }
+                _ => {
+                    self.write(&format!("{}::{}", type_(&function.ty), function.name));
+                    self.arguments(&function.arguments);
+                }
+            },
+            AccessExpression::Member(access) => {
+                self.operand(&access.inner, POSTFIX);
+                self.write(&format!(".{}", access.name));
+            }
+            AccessExpression::Tuple(access) => {
+                self.operand(&access.tuple, POSTFIX);
+                self.write(&format!(".{}", access.index));
+            }
+        }
+    }
+
+    /// Prints a struct initializer, on one line if it fits and contains no comments.
+    fn struct_expression(&mut self, struct_: &StructExpression) {
+        self.write(&format!("{} ", struct_.name));
+        if struct_.members.is_empty() && !self.has_comments_within(struct_.span) {
+            return self.write("{}");
+        }
+
+        let mut flat = Printer::new("", self.start_pos, Vec::new());
+        flat.max_width = usize::MAX;
+        flat.members_on_one_line(&struct_.members);
+        if self.column() + flat.out.len() <= self.max_width && !self.has_comments_within(struct_.span) {
+            return self.write(&flat.out);
+        }
+
+        self.open(self.find_open_brace(struct_.name.span.hi));
+        for member in &struct_.members {
+            self.begin(member.span.lo);
+            self.member(member);
+            self.write(",");
+            self.end(member.span.hi);
+        }
+        self.close(struct_.span.hi);
+    }
+
+    /// Prints the members of a struct initializer on one line, e.g. `{ a: 1u8, b }`.
+    fn members_on_one_line(&mut self, members: &[StructVariableInitializer]) {
+        self.write("{ ");
+        for (i, member) in members.iter().enumerate() {
+            if i > 0 {
+                self.write(", ");
+            }
+            self.member(member);
+        }
+        self.write(" }");
+    }
+
+    /// Prints a member of a struct initializer, e.g. `a: 1u8`.
+    fn member(&mut self, member: &StructVariableInitializer) {
+        self.write(&member.identifier.to_string());
+        if let Some(expression) = &member.expression {
+            self.write(": ");
+            self.expression(expression);
+        }
+    }
+
+    /// Writes `{` to open a scope whose brace ends at `brace`, followed by the comments on the same line.
+    fn open(&mut self, brace: BytePos) {
+        self.write("{");
+        self.end(brace + BytePos::from_usize(1));
+        self.indent += 1;
+        self.at_scope_start = true;
+    }
+
+    /// Writes `}` to close a scope that ends at `hi`, after the comments that remain within it.
+    fn close(&mut self, hi: BytePos) {
+        self.leading_comments(hi);
+        self.indent -= 1;
+        self.at_scope_start = false;
+        self.write("}");
+        self.last = hi;
+    }
+
+    /// Starts a line for the node at `lo`, printing the comments before it and keeping a blank line before it.
+    fn begin(&mut self, lo: BytePos) {
+        self.leading_comments(lo);
+        self.separate(lo);
+    }
+
+    /// Prints the comments that start before `lo`, each on its own line.
+    fn leading_comments(&mut self, lo: BytePos) {
+        while self.comments.front().map_or(false, |comment| comment.span.lo < lo) {
+            let comment = self.comments.pop_front().unwrap();
+            self.separate(comment.span.lo);
+            self.write_comment(&comment);
+            self.newline();
+        }
+    }
+
+    /// Keeps a single blank line before the node or comment at `lo`, if there is one in the source.
+    fn separate(&mut self, lo: BytePos) {
+        if !self.at_scope_start && self.text(self.last, lo).matches('\n').count() > 1 {
+            self.newline();
+        }
+        self.at_scope_start = false;
+    }
+
+    /// Ends the line of the node that ends at `hi`, after the comments within the node and on the same line.
+    fn end(&mut self, hi: BytePos) {
+        self.last = self.last.max(hi);
+        let mut after_line_comment = false;
+        while self
+            .comments
+            .front()
+            .map_or(false, |comment| comment.span.lo < hi || !self.text(self.last, comment.span.lo).contains('\n'))
+        {
+            let comment = self.comments.pop_front().unwrap();
+            match after_line_comment {
+                true => self.newline(),
+                false => self.write(" "),
+            }
+            self.write_comment(&comment);
+            after_line_comment = comment.content.starts_with("//");
+        }
+        self.last = self.last.max(hi);
+        self.newline();
+    }
+
+    /// Writes a comment, without the line break that ends a line comment.
+    fn write_comment(&mut self, comment: &Comment) {
+        let content = comment.content.trim_end();
+        self.write(content);
+        self.last = comment.span.lo + BytePos::from_usize(content.len());
+    }
+
+    /// Returns `true` if there are comments within the `span` that have not been printed yet.
+    fn has_comments_within(&self, span: Span) -> bool {
+        self.comments.iter().take_while(|comment| comment.span.lo < span.hi).any(|comment| comment.span.lo > span.lo)
+    }
+
+    /// Returns the position of the `{` at or after `from`, skipping over comments.
+    fn find_open_brace(&self, from: BytePos) -> BytePos {
+        let mut position = from;
+        while position < self.end_pos() {
+            match self.comments.iter().find(|comment| comment.span.lo == position) {
+                Some(comment) => position = comment.span.hi,
+                None if self.text(position, self.end_pos()).starts_with('{') => return position,
+                None => position = position + BytePos::from_usize(1),
+            }
+        }
+        from
+    }
+
+    /// Returns the position of the end of the source code.
+    fn end_pos(&self) -> BytePos {
+        self.start_pos + BytePos::from_usize(self.source.len())
+    }
+
+    /// Returns the source code from `lo` to `hi`, or nothing if `hi` is before `lo`.
+    fn text(&self, lo: BytePos, hi: BytePos) -> &str {
+        let offset =
+            |position: BytePos| ((position.0.saturating_sub(self.start_pos.0)) as usize).min(self.source.len());
+        self.source.get(offset(lo)..offset(hi)).unwrap_or_default()
+    }
+
+    /// Returns the column at which the next text is written.
+    fn column(&self) -> usize {
+        let line = self.out.rsplit('\n').next().unwrap_or_default();
+        line.len() + if self.at_line_start { self.indent * INDENT.len() } else { 0 }
+    }
+
+    /// Writes `text` on the current line, indenting the line if `text` is the first on it.
+    fn write(&mut self, text: &str) {
+        if self.at_line_start && !text.is_empty() {
+            self.out.push_str(&INDENT.repeat(self.indent));
+            self.at_line_start = false;
+        }
+        self.out.push_str(text);
+    }
+
+    /// Ends the current line.
+    fn newline(&mut self) {
+        self.out.push('\n');
+        self.at_line_start = true;
+    }
+}
+
+/// An item in a program scope.
+enum Item<'a> {
+    Const(&'a ConstDeclaration),
+    Struct(&'a Struct),
+    Mapping(&'a Mapping),
+    Function(&'a Function),
+}
+
+impl Item<'_> {
+    /// Returns the position where the item starts, including any annotations.
+    fn lo(&self) -> BytePos {
+        match self {
+            Item::Const(declaration) => declaration.span.lo,
+            Item::Struct(struct_) => struct_.span.lo,
+            Item::Mapping(mapping) => mapping.span.lo,
+            Item::Function(function) => function.annotations.first().map_or(function.span.lo, |a| a.span.lo),
+        }
+    }
+}
+
+/// Returns the precedence of the expression, where an operand needs parentheses if its precedence is lower than required.
+fn precedence_of(expression: &Expression) -> u8 {
+    match expression {
+        Expression::Ternary(_) => 0,
+        Expression::Binary(binary) if is_operator(binary) => binary_precedence(binary.op).unwrap_or(POSTFIX),
+        Expression::Cast(_) => CAST,
+        Expression::Unary(unary) if unary.span.lo < unary.receiver.span().lo => PREFIX,
+        Expression::Literal(
+            Literal::Integer(_, value, ..) | Literal::Field(value, ..) | Literal::Scalar(value, ..),
+        ) if value.starts_with('-') => PREFIX,
+        Expression::Literal(Literal::Group(group)) if matches!(group.as_ref(), GroupLiteral::Single(value, ..) if value.starts_with('-')) => {
+            PREFIX
+        }
+        _ => POSTFIX,
+    }
+}
+
+/// Returns the precedence of the binary operator, or `None` if it can only be invoked as a method.
+fn binary_precedence(op: BinaryOperation) -> Option<u8> {
+    use BinaryOperation::*;
+    Some(match op {
+        Or => 1,
+        And => 2,
+        Eq | Neq => 3,
+        Lt | Lte | Gt | Gte => 4,
+        Xor => 5,
+        BitwiseOr => 6,
+        BitwiseAnd => 7,
+        Shl | Shr => 8,
+        Add | Sub => 9,
+        Mul | Div | Rem => 10,
+        Pow => 11,
+        _ => return None,
+    })
+}
+
+/// Returns `true` if the binary expression is written with an operator, e.g. `a + b` rather than `a.add(b)`.
+/// The span of a method call ends at its closing parenthesis, after the argument.
+fn is_operator(binary: &BinaryExpression) -> bool {
+    binary_precedence(binary.op).is_some() && binary.span.hi == binary.right.span().hi
+}
+
+/// Returns the mode as a prefix, e.g. `public `.
+fn mode(mode: Mode) -> String {
+    match mode {
+        Mode::None => String::new(),
+        mode => format!("{mode} "),
+    }
+}
+
+/// Returns a record of another program, e.g. `token.leo/token.record`.
+fn external_record(external: &External) -> String {
+    format!("{}.leo/{}.record", external.program_name, external.record)
+}
+
+/// Returns the type as it is written in Leo.
+fn type_(type_: &Type) -> String {
+    match type_ {
+        Type::Array(array) => format!("[{}; {}]", self::type_(array.element_type()), array.length()),
+        Type::Boolean => "bool".to_string(),
+        Type::Tuple(tuple) => format!("({})", tuple.elements().iter().map(self::type_).collect::<Vec<_>>().join(", ")),
+        type_ => type_.to_string(),
+    }
+}
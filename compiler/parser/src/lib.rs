@@ -31,6 +31,9 @@ pub(crate) use tokenizer::*;
 pub mod parser;
 pub use parser::*;
 
+pub mod formatter;
+pub use formatter::*;
+
 use leo_ast::{input::InputData, Ast, NodeBuilder, ProgramInput};
 use leo_errors::{emitter::Handler, Result};
 
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{tokenizer::*, Token};
+use crate::{tokenizer::*, Comment, Token};
 
 use leo_ast::*;
 use leo_errors::{emitter::Handler, ParserError, ParserWarning, Result};
@@ -41,6 +41,8 @@ pub(crate) struct ParserContext<'a> {
     pub(crate) disallow_struct_construction: bool,
     /// true if parsing an identifier inside an input file.
     pub(crate) allow_identifier_underscores: bool,
+    /// The comments set aside from the tokens, in source order.
+    pub(crate) comments: Vec<Comment>,
 }
 
 /// Dummy span used to appease borrow checker.
@@ -48,9 +50,11 @@ const DUMMY_EOF: SpannedToken = SpannedToken { token: Token::Eof, span: Span::du
 
 impl<'a> ParserContext<'a> {
     /// Returns a new [`ParserContext`] type given a vector of tokens.
-    pub fn new(handler: &'a Handler, node_builder: &'a NodeBuilder, mut tokens: Vec<SpannedToken>) -> Self {
-        // Strip out comments.
-        tokens.retain(|x| !matches!(x.token, Token::CommentLine(_) | Token::CommentBlock(_)));
+    pub fn new(handler: &'a Handler, node_builder: &'a NodeBuilder, tokens: Vec<SpannedToken>) -> Self {
+        // Strip out comments, setting them aside for the formatter.
+        let (comments, mut tokens): (Vec<_>, Vec<_>) =
+            tokens.into_iter().partition(|x| matches!(x.token, Token::CommentLine(_) | Token::CommentBlock(_)));
+        let comments = comments.into_iter().map(|x| Comment { content: x.token.to_string(), span: x.span }).collect();
         // For performance we reverse so that we get cheap `.pop()`s.
         tokens.reverse();
 
@@ -63,6 +67,7 @@ impl<'a> ParserContext<'a> {
             prev_token: token.clone(),
             token,
             tokens,
+            comments,
         };
         p.bump();
         p
@@ -19,7 +19,13 @@ use crate::parse_ast;
 use leo_errors::{CompilerError, ParserError, Result};
 use leo_span::{source_map::FileName, symbol::with_session_globals};
 
-use std::fs;
+use std::{cell::RefCell, fs};
+
+thread_local! {
+    /// The names of the imported programs that are currently being parsed, outermost first,
+    /// along with the spans of the import statements that imported them.
+    static IMPORT_STACK: RefCell<Vec<(Symbol, Span)>> = RefCell::new(Vec::new());
+}
 
 impl ParserContext<'_> {
     /// Returns a [`Program`] AST if all tokens can be consumed and represent a valid Leo program.
@@ -97,8 +103,28 @@ impl ParserContext<'_> {
             return Err(CompilerError::import_not_found(import_file_path.display(), self.prev_token.span).into());
         }
 
+        // Check that the import does not depend on itself, which would otherwise lead to infinite recursion.
+        // The error points at every import statement in the cycle.
+        let cycle = IMPORT_STACK.with(|stack| {
+            let stack = stack.borrow();
+            stack.iter().position(|(name, _)| *name == import_name.name).map(|index| stack[index..].to_vec())
+        });
+        if let Some(cycle) = cycle {
+            let chain = cycle
+                .iter()
+                .map(|(name, _)| name)
+                .chain([&import_name.name])
+                .map(|name| format!("{name}.leo"))
+                .collect::<Vec<_>>()
+                .join(" -> ");
+            let mut error = CompilerError::cyclic_import(chain, start + end);
+            for (_, span) in cycle {
+                error = error.with_related_span(span);
+            }
+            return Err(error.into());
+        }
+
         // Read the import file into string.
-        // Todo: protect against cyclic imports.
         let program_string =
             fs::read_to_string(&import_file_path).map_err(|e| CompilerError::file_read_error(&import_file_path, e))?;
 
@@ -109,7 +135,11 @@ impl ParserContext<'_> {
         let prg_sf = with_session_globals(|s| s.source_map.new_source(&program_string, name));
 
         // Use the parser to construct the imported abstract syntax tree (ast).
-        let program_ast = parse_ast(self.handler, self.node_builder, &prg_sf.src, prg_sf.start_pos)?;
+        // Note that the import is removed from the stack before any error is propagated.
+        IMPORT_STACK.with(|stack| stack.borrow_mut().push((import_name.name, start + end)));
+        let program_ast = parse_ast(self.handler, self.node_builder, &prg_sf.src, prg_sf.start_pos);
+        IMPORT_STACK.with(|stack| stack.borrow_mut().pop());
+        let program_ast = program_ast?;
 
         Ok((import_name.name, (program_ast.into_repr(), start + end)))
     }
@@ -392,9 +422,32 @@ impl ParserContext<'_> {
 
         // TODO: Verify that this check is sound.
         // Check that there is no whitespace in between the `@` symbol and identifier.
-        match identifier.span.hi.0 - start.lo.0 > 1 + identifier.name.to_string().len() as u32 {
-            true => Err(ParserError::space_in_annotation(span).into()),
-            false => Ok(Annotation { identifier, span, id: self.node_builder.next_id() }),
+        if identifier.span.hi.0 - start.lo.0 > 1 + identifier.name.to_string().len() as u32 {
+            return Err(ParserError::space_in_annotation(span).into());
+        }
+
+        // Parse the arguments, e.g. `(should_fail)`, if they exist.
+        let (arguments, span) = match self.check(&Token::LeftParen) {
+            true => {
+                let (arguments, _, end) = self.parse_paren_comma_list(|p| p.parse_annotation_argument().map(Some))?;
+                (arguments, span + end)
+            }
+            false => (Vec::new(), span),
+        };
+
+        Ok(Annotation { identifier, arguments, span, id: self.node_builder.next_id() })
+    }
+
+    /// Returns an annotation argument, which is either an identifier or an integer, e.g. `100_000` in
+    /// `@max_constraints(100_000)`.
+    fn parse_annotation_argument(&mut self) -> Result<AnnotationArgument> {
+        match &self.token.token {
+            Token::Integer(value) => {
+                let value = value.replace('_', "");
+                self.bump();
+                Ok(AnnotationArgument::Integer(value, self.prev_token.span, self.node_builder.next_id()))
+            }
+            _ => Ok(AnnotationArgument::Identifier(self.expect_identifier()?)),
         }
     }
 
@@ -16,18 +16,22 @@
 
 use super::*;
 
-use leo_errors::{ParserError, Result};
+use leo_errors::{InputError, ParserError, Result};
+use leo_span::sym;
 
 impl ParserContext<'_> {
     /// Returns a [`ParsedInputFile`] struct filled with the data acquired in the file.
     pub(crate) fn parse_input_file(&mut self) -> Result<InputAst> {
         // Allow underscores in identifiers for input record declarations.
         self.allow_identifier_underscores = true;
+        let mut includes = Vec::new();
         let mut sections = Vec::new();
 
         while self.has_next() {
             if self.check(&Token::LeftSquare) {
                 sections.push(self.parse_section()?);
+            } else if self.peek_is_include() {
+                includes.push(self.parse_include()?);
             } else {
                 return Err(ParserError::unexpected_token(self.token.token.clone(), self.token.span).into());
             }
@@ -36,26 +40,56 @@ impl ParserContext<'_> {
         // Do not allow underscores in identifiers outside of input files.
         self.allow_identifier_underscores = false;
 
-        Ok(InputAst { sections })
+        Ok(InputAst { includes, sections })
+    }
+
+    /// Returns `true` if the next tokens are `include "<path>"`, rather than a definition named `include`.
+    fn peek_is_include(&self) -> bool {
+        matches!(
+            (&self.token.token, self.look_ahead(1, |t| &t.token)),
+            (Token::Identifier(name), Token::StaticString(_)) if *name == sym::include
+        )
+    }
+
+    /// Parses an include of another input file:
+    /// `include "<path>";`
+    /// Returns [`Include`].
+    fn parse_include(&mut self) -> Result<Include> {
+        let start = self.expect_identifier()?.span;
+        let path = match &self.token.token {
+            Token::StaticString(path) => path.clone(),
+            token => return Err(ParserError::unexpected_token(token.clone(), self.token.span).into()),
+        };
+        self.bump();
+        let end = self.expect(&Token::Semicolon)?;
+
+        Ok(Include { path, span: start + end })
     }
 
     /// Parses particular section in the Input or State file.
     /// `
-    /// [<identifier>]
+    /// [<identifier>(.<identifier>)?]
     /// <...definition>
     /// `
     /// Returns [`Section`].
     fn parse_section(&mut self) -> Result<Section> {
         self.expect(&Token::LeftSquare)?;
         let section = self.expect_identifier()?;
+        let variant = match self.eat(&Token::Dot) {
+            true => Some(self.expect_identifier()?.name),
+            false => None,
+        };
         self.expect(&Token::RightSquare)?;
 
         let mut definitions = Vec::new();
         while let Token::Constant | Token::Public | Token::Identifier(_) = self.token.token {
+            if self.peek_is_include() {
+                break;
+            }
             definitions.push(self.parse_input_definition()?);
         }
 
-        Ok(Section { name: section.name, span: section.span, definitions })
+        Ok(Section { name: section.name, variant, span: section.span, definitions })
     }
 
     /// Parses a single parameter definition:
@@ -68,9 +102,79 @@ impl ParserContext<'_> {
         self.expect(&Token::Colon)?;
         let (type_, span) = self.parse_type()?;
         self.expect(&Token::Assign)?;
-        let value = self.parse_unary_expression()?;
+        let value = self.parse_input_value(&type_)?;
         self.expect(&Token::Semicolon)?;
 
         Ok(Definition { mode, name, type_, value, span })
     }
+
+    /// Parses the value of an input definition of type `type_`.
+    /// In addition to ordinary expressions, this accepts array literals `[a, b, c]`,
+    /// the repeat form `[a; N]`, which is expanded into `N` copies of `a`,
+    /// and struct values without a name, `{ x: a, y: b }`, whose name is taken from `type_`.
+    /// Returns [`Expression`].
+    fn parse_input_value(&mut self, type_: &Type) -> Result<Expression> {
+        let element_type = match (type_, &self.token.token) {
+            (Type::Identifier(identifier), Token::LeftCurly) => {
+                let identifier =
+                    Identifier { name: identifier.name, span: self.token.span, id: self.node_builder.next_id() };
+                return self.parse_struct_init_expression(identifier);
+            }
+            (Type::Array(array_type), Token::LeftSquare) => array_type.element_type().clone(),
+            (_, Token::LeftSquare) => Type::Err,
+            _ => return self.parse_unary_expression(),
+        };
+
+        let start = self.expect(&Token::LeftSquare)?;
+        let first = self.parse_input_value(&element_type)?;
+
+        let elements = if self.eat(&Token::Semicolon) {
+            let (length, span) = self.eat_whole_number()?;
+            // Check the length against the declared type before building the array, since it may be very large.
+            match type_ {
+                Type::Array(array_type) if array_type.length() == length.value() => {}
+                Type::Array(array_type) => {
+                    return Err(InputError::array_length_mismatch(array_type.length(), length.value(), span).into());
+                }
+                _ => return Err(InputError::unexpected_type(type_, "array", span).into()),
+            }
+            // Each copy of the element is a distinct node.
+            let mut elements = vec![first; length.value()];
+            elements.iter_mut().skip(1).for_each(|element| self.renumber(element));
+            elements
+        } else {
+            let mut elements = vec![first];
+            while self.eat(&Token::Comma) {
+                if self.check(&Token::RightSquare) {
+                    break;
+                }
+                elements.push(self.parse_input_value(&element_type)?);
+            }
+            elements
+        };
+        let end = self.expect(&Token::RightSquare)?;
+
+        Ok(Expression::Array(ArrayExpression { elements, span: start + end, id: self.node_builder.next_id() }))
+    }
+
+    /// Gives the input value `value`, and each of the nodes in it, a new ID.
+    /// Input values are literals, negations, arrays, and structs; other expressions are rejected after parsing.
+    fn renumber(&self, value: &mut Expression) {
+        value.set_id(self.node_builder.next_id());
+        match value {
+            Expression::Array(array) => array.elements.iter_mut().for_each(|element| self.renumber(element)),
+            Expression::Unary(unary) => self.renumber(&mut unary.receiver),
+            Expression::Struct(struct_expression) => {
+                struct_expression.name.id = self.node_builder.next_id();
+                for member in struct_expression.members.iter_mut() {
+                    member.id = self.node_builder.next_id();
+                    member.identifier.id = self.node_builder.next_id();
+                    if let Some(expression) = &mut member.expression {
+                        self.renumber(expression);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
 }
@@ -22,12 +22,15 @@
 use crate::{tokenizer::*, Token};
 
 use leo_ast::*;
-use leo_errors::{emitter::Handler, Result};
-use leo_span::Span;
+use leo_errors::{emitter::Handler, CompilerError, InputError, Result};
+use leo_span::{symbol::with_session_globals, Span};
 
 use indexmap::IndexMap;
 use leo_span::span::BytePos;
-use std::unreachable;
+use std::{
+    path::{Path, PathBuf},
+    unreachable,
+};
 
 mod context;
 pub(super) use context::ParserContext;
@@ -45,6 +48,28 @@ pub fn parse(handler: &Handler, node_builder: &NodeBuilder, source: &str, start_
     tokens.parse_program()
 }
 
+/// A comment in Leo source code, which the parser sets aside rather than discarding.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Comment {
+    /// The text of the comment, including its delimiters.
+    pub content: String,
+    /// The span of the comment.
+    pub span: Span,
+}
+
+/// Creates a new program from the given source code text, along with the comments in it.
+pub fn parse_with_comments(
+    handler: &Handler,
+    node_builder: &NodeBuilder,
+    source: &str,
+    start_pos: BytePos,
+) -> Result<(Program, Vec<Comment>)> {
+    let mut tokens = ParserContext::new(handler, node_builder, crate::tokenize(source, start_pos)?);
+
+    let program = tokens.parse_program()?;
+    Ok((program, tokens.comments))
+}
+
 /// Parses an input file at the given file `path` and `source` code text.
 pub fn parse_input(
     handler: &Handler,
@@ -56,3 +81,51 @@ pub fn parse_input(
 
     tokens.parse_input_file()
 }
+
+/// Parses the input file at `path`, along with the input files it includes.
+/// Included paths are relative to the directory of the including file,
+/// and the sections of an included file come before those of the including file.
+/// A file that is included more than once only contributes its sections the first time.
+pub fn parse_input_file(handler: &Handler, node_builder: &NodeBuilder, path: &Path) -> Result<InputAst> {
+    parse_input_file_with_includes(handler, node_builder, path, &mut Vec::new(), &mut Vec::new())
+}
+
+/// Parses the input file at `path`, where `including` are the files that (transitively) include it,
+/// and `included` are the files that have already been parsed.
+fn parse_input_file_with_includes(
+    handler: &Handler,
+    node_builder: &NodeBuilder,
+    path: &Path,
+    including: &mut Vec<PathBuf>,
+    included: &mut Vec<PathBuf>,
+) -> Result<InputAst> {
+    let input_sf = with_session_globals(|s| s.source_map.load_file(path))
+        .map_err(|e| CompilerError::file_read_error(path, e))?;
+    let mut input_ast = parse_input(handler, node_builder, &input_sf.src, input_sf.start_pos)?;
+
+    let canonical_path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    including.push(canonical_path.clone());
+    included.push(canonical_path);
+    let mut sections = Vec::new();
+    for include in input_ast.includes.iter() {
+        let include_path = path.parent().unwrap_or_else(|| Path::new("")).join(&include.path);
+        if !include_path.exists() {
+            return Err(InputError::include_not_found(include_path.display(), include.span).into());
+        }
+        let canonical_include_path = include_path.canonicalize().unwrap_or_else(|_| include_path.clone());
+        if including.contains(&canonical_include_path) {
+            return Err(InputError::include_cycle(include_path.display(), include.span).into());
+        }
+        if included.contains(&canonical_include_path) {
+            continue;
+        }
+        sections.extend(
+            parse_input_file_with_includes(handler, node_builder, &include_path, including, included)?.sections,
+        );
+    }
+    including.pop();
+
+    sections.append(&mut input_ast.sections);
+    input_ast.sections = sections;
+    Ok(input_ast)
+}
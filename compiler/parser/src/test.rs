@@ -133,7 +133,26 @@ impl Namespace for ParseNamespace {
     }
 
     fn run_test(&self, test: Test) -> Result<Value, String> {
-        create_session_if_not_set_then(|s| with_handler(tokenize(test, s)?, |p| p.parse_program()).map(yaml_or_fail))
+        // Imports are resolved in the working directory, which a test can set with `cwd`.
+        let cwd = test.config.extra.get("cwd").map(|cwd| {
+            let cwd = test.path.parent().unwrap().join(cwd.as_str().unwrap());
+            cwd.canonicalize().unwrap()
+        });
+        let previous = std::env::current_dir().unwrap();
+        if let Some(cwd) = &cwd {
+            std::env::set_current_dir(cwd).unwrap();
+        }
+
+        let result = create_session_if_not_set_then(|s| {
+            with_handler(tokenize(test, s)?, |p| p.parse_program()).map(yaml_or_fail)
+        });
+        std::env::set_current_dir(previous).unwrap();
+
+        // Print the paths of imported files relative to `cwd`, so that expectations do not depend on the checkout.
+        match cwd {
+            Some(cwd) => result.map_err(|errors| errors.replace(&format!("{}/", cwd.display()), "")),
+            None => result,
+        }
     }
 }
 
@@ -214,6 +233,26 @@ impl Namespace for InputNamespace {
     }
 }
 
+struct FormatNamespace;
+
+impl Namespace for FormatNamespace {
+    fn parse_type(&self) -> ParseType {
+        ParseType::Whole
+    }
+
+    fn run_test(&self, test: Test) -> Result<Value, String> {
+        create_session_if_not_set_then(|s| {
+            let sf = s.source_map.new_source(&test.content, FileName::Custom("test".into()));
+            let (handler, buf) = Handler::new_with_buf();
+            // Note that `format_source` checks that the formatted program is equivalent to the original, and is stable.
+            handler
+                .extend_if_error(crate::format_source(&handler, &sf.src, sf.start_pos))
+                .map(Value::String)
+                .map_err(|_| buf.extract_errs().to_string())
+        })
+    }
+}
+
 struct TestRunner;
 
 impl Runner for TestRunner {
@@ -225,6 +264,7 @@ impl Runner for TestRunner {
             "Serialize" => Box::new(SerializeNamespace),
             "Input" => Box::new(InputNamespace),
             "Token" => Box::new(TokenNamespace),
+            "Format" => Box::new(FormatNamespace),
             _ => return None,
         })
     }
@@ -0,0 +1,116 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+use leo_ast::{
+    ConstDeclaration,
+    ExpressionReconstructor,
+    Function,
+    Input,
+    InputAst,
+    Mode,
+    Node,
+    NodeBuilder,
+    ProgramReconstructor,
+    Statement,
+    StatementReconstructor,
+    Variant,
+};
+use leo_errors::{emitter::Handler, InputError};
+
+pub struct ConstantInputInliner<'a> {
+    /// The input file, which provides the values of the constant inputs.
+    pub(crate) input_ast: &'a InputAst,
+    /// An error handler for missing and inconsistent values in the input file.
+    pub(crate) handler: &'a Handler,
+    /// A counter to generate unique node IDs.
+    pub(crate) node_builder: &'a NodeBuilder,
+}
+
+impl<'a> ConstantInputInliner<'a> {
+    /// Initializes a new `ConstantInputInliner`.
+    pub fn new(input_ast: &'a InputAst, handler: &'a Handler, node_builder: &'a NodeBuilder) -> Self {
+        Self { input_ast, handler, node_builder }
+    }
+}
+
+impl ExpressionReconstructor for ConstantInputInliner<'_> {
+    type AdditionalOutput = ();
+}
+
+impl StatementReconstructor for ConstantInputInliner<'_> {}
+
+impl ProgramReconstructor for ConstantInputInliner<'_> {
+    fn reconstruct_function(&mut self, mut input: Function) -> Function {
+        if input.variant != Variant::Transition {
+            return input;
+        }
+
+        // The values are taken from every section for the function, e.g. `[main]` and `[main.testnet]`.
+        // The unnamed section comes first, so that it is the one that other sections are checked against.
+        let mut sections =
+            self.input_ast.sections.iter().filter(|section| section.name == input.identifier.name).collect::<Vec<_>>();
+        sections.sort_by_key(|section| section.variant.is_some());
+        let definitions = sections
+            .into_iter()
+            .flat_map(|section| section.definitions.iter())
+            .filter(|definition| definition.mode == Mode::Constant)
+            .collect::<Vec<_>>();
+
+        let mut constants = Vec::new();
+        input.input.retain(|function_input| match function_input {
+            Input::Internal(function_input) if function_input.mode == Mode::Constant => {
+                let mut values =
+                    definitions.iter().filter(|definition| definition.name.name == function_input.identifier.name);
+                match values.next() {
+                    Some(definition) => {
+                        // Only one program is compiled, so every section must give the same value.
+                        for other in values.filter(|other| other.value.to_string() != definition.value.to_string()) {
+                            self.handler.emit_err(
+                                InputError::conflicting_constant_input(
+                                    function_input.identifier,
+                                    input.identifier,
+                                    other.value.span(),
+                                )
+                                .with_related_span(definition.value.span()),
+                            );
+                        }
+                        constants.push(Statement::Const(ConstDeclaration {
+                            place: function_input.identifier,
+                            type_: function_input.type_.clone(),
+                            value: definition.value.clone(),
+                            span: function_input.span,
+                            id: self.node_builder.next_id(),
+                        }));
+                        false
+                    }
+                    None => {
+                        self.handler.emit_err(InputError::missing_constant_input(
+                            function_input.identifier,
+                            input.identifier,
+                            function_input.span,
+                        ));
+                        true
+                    }
+                }
+            }
+            _ => true,
+        });
+
+        // Define the constants at the start of the function body.
+        constants.append(&mut input.block.statements);
+        input.block.statements = constants;
+        input
+    }
+}
@@ -0,0 +1,61 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+//! The Constant Inputs pass replaces the `constant` inputs of `transition`s with the values given in the input file.
+//! The pass is run directly after parsing, so that the values are type checked and propagated like any other constant.
+//!
+//! Consider the following Leo code and input file.
+//! ```leo
+//! transition main(constant fee: u64, public amount: u64) -> u64 {
+//!     return amount - fee;
+//! }
+//! ```
+//! ```leo
+//! [main]
+//! constant fee: u64 = 10u64;
+//! public amount: u64 = 100u64;
+//! ```
+//!
+//! The constant inputs pass produces the following code.
+//! ```leo
+//! transition main(public amount: u64) -> u64 {
+//!     const fee: u64 = 10u64;
+//!     return amount - fee;
+//! }
+//! ```
+//! The values are taken from every section for the function, including variants such as `[main.testnet]`.
+//! Since only one program is compiled, the sections must agree on the value of each constant input.
+//! Constant inputs without a value in the input file are reported, rather than compiled as other inputs.
+
+mod constant_input_inliner;
+pub use constant_input_inliner::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, InputAst, NodeBuilder, ProgramReconstructor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for ConstantInputInliner<'a> {
+    type Input = (Ast, &'a InputAst, &'a Handler, &'a NodeBuilder);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, input_ast, handler, node_builder): Self::Input) -> Self::Output {
+        let mut reconstructor = ConstantInputInliner::new(input_ast, handler, node_builder);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(Ast::new(program))
+    }
+}
@@ -269,6 +269,12 @@ impl StatementReconstructor for Flattener<'_> {
     /// Transforms a return statement into an empty block statement.
     /// Stores the arguments to the return statement, which are later folded into a single return statement at the end of the function.
     fn reconstruct_return(&mut self, input: ReturnStatement) -> (Statement, Self::AdditionalOutput) {
+        // If an unguarded return has already been encountered, then this return is unreachable.
+        // This can occur once the loop unroller has pruned a branch with a constant condition, e.g. `if true { return a; } return b;`.
+        if let Some((None, _)) = self.returns.last() {
+            return (Statement::dummy(Default::default(), self.node_builder.next_id()), Default::default());
+        }
+
         // Construct the associated guard.
         let guard = self.construct_guard();
 
@@ -0,0 +1,364 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::evaluate_statement::integer;
+use crate::Interpreter;
+
+use leo_ast::{
+    AccessExpression,
+    BinaryOperation,
+    Expression,
+    IntegerType,
+    Literal,
+    Node,
+    Type,
+    UnaryOperation,
+    Value,
+};
+use leo_errors::{InterpreterError, Result};
+use leo_span::{sym, Span};
+
+use indexmap::IndexMap;
+use snarkvm_console::{
+    network::Testnet3,
+    prelude::{Double, Inverse, Pow, Square, SquareRoot, Zero},
+    types::Field as ConsoleField,
+};
+use std::str::FromStr;
+
+type Field = ConsoleField<Testnet3>;
+
+impl<'a> Interpreter<'a> {
+    /// Evaluates an expression to a value, with the variables that are in scope.
+    pub fn evaluate_expression(&mut self, expression: &Expression) -> Result<Value> {
+        match expression {
+            Expression::Access(access) => self.evaluate_access(access),
+            Expression::Array(array) => Ok(Value::Array(self.evaluate_expressions(&array.elements)?)),
+            Expression::Binary(binary) => {
+                let left = self.evaluate_expression(&binary.left)?;
+                let right = self.evaluate_expression(&binary.right)?;
+                evaluate_binary(left, right, binary.op, binary.span)
+            }
+            Expression::Call(call) => match (&*call.function, &call.external) {
+                (Expression::Identifier(function), None) => {
+                    let arguments = self.evaluate_expressions(&call.arguments)?;
+                    self.call(function.name, arguments)
+                }
+                _ => Err(InterpreterError::unsupported("calls to other programs", call.span).into()),
+            },
+            Expression::Cast(cast) => {
+                let value = self.evaluate_expression(&cast.expression)?;
+                evaluate_cast(value, &cast.type_, cast.span)
+            }
+            Expression::Struct(struct_) => {
+                let mut members = IndexMap::new();
+                for member in struct_.members.iter() {
+                    let value = match &member.expression {
+                        Some(expression) => self.evaluate_expression(expression)?,
+                        // A member without an expression is initialized with the variable of the same name.
+                        None => self.evaluate_expression(&Expression::Identifier(member.identifier))?,
+                    };
+                    members.insert(member.identifier.name, value);
+                }
+                Ok(Value::Struct(struct_.name, members))
+            }
+            Expression::Err(_) => {
+                unreachable!("`ErrExpression`s should not be in the AST at this phase of compilation.")
+            }
+            Expression::Identifier(identifier) => {
+                match self.variables.get(&identifier.name).or_else(|| self.consts.get(&identifier.name)) {
+                    Some(value) => Ok(value.clone()),
+                    None => Err(InterpreterError::unsupported(format!("`{identifier}`"), identifier.span).into()),
+                }
+            }
+            Expression::Literal(literal) => evaluate_literal(literal),
+            Expression::Ternary(ternary) => match self.evaluate_condition(&ternary.condition)? {
+                true => self.evaluate_expression(&ternary.if_true),
+                false => self.evaluate_expression(&ternary.if_false),
+            },
+            Expression::Tuple(tuple) => Ok(Value::Tuple(self.evaluate_expressions(&tuple.elements)?)),
+            Expression::Unary(unary) => {
+                let value = self.evaluate_expression(&unary.receiver)?;
+                evaluate_unary(value, unary.op, unary.span)
+            }
+            Expression::Unit(_) => Ok(Value::Tuple(Vec::new())),
+        }
+    }
+
+    /// Evaluates a list of expressions, in order.
+    fn evaluate_expressions(&mut self, expressions: &[Expression]) -> Result<Vec<Value>> {
+        expressions.iter().map(|expression| self.evaluate_expression(expression)).collect()
+    }
+
+    /// Evaluates an access expression, e.g. `a[0u8]`, `a.b`, or `a.0`.
+    fn evaluate_access(&mut self, access: &AccessExpression) -> Result<Value> {
+        match access {
+            AccessExpression::Array(access) => {
+                let array = self.evaluate_expression(&access.array)?;
+                let index = self.evaluate_expression(&access.index)?;
+                match array {
+                    Value::Array(mut elements) => {
+                        let length = elements.len();
+                        match u128::try_from(&index).ok().and_then(|index| usize::try_from(index).ok()) {
+                            Some(index) if index < length => Ok(elements.swap_remove(index)),
+                            _ => Err(InterpreterError::index_out_of_bounds(index, length, access.span).into()),
+                        }
+                    }
+                    // Unreachable because type checking ensures that only arrays are indexed.
+                    value => unreachable!("Expected an array, found `{value}`."),
+                }
+            }
+            AccessExpression::AssociatedConstant(constant) => {
+                Err(InterpreterError::unsupported(format!("`{}::{}`", constant.ty, constant.name), constant.span).into())
+            }
+            AccessExpression::AssociatedFunction(function) => {
+                Err(InterpreterError::unsupported(format!("`{}::{}`", function.ty, function.name), function.span).into())
+            }
+            AccessExpression::Member(access) => match &*access.inner {
+                // The caller, the signer, and the block height are only known when the program is executed on-chain.
+                Expression::Identifier(identifier) if matches!(identifier.name, sym::SelfLower | sym::block) => {
+                    Err(InterpreterError::unsupported(format!("`{}.{}`", identifier, access.name), access.span).into())
+                }
+                inner => match self.evaluate_expression(inner)? {
+                    Value::Struct(_, mut members) => Ok(members
+                        .swap_remove(&access.name.name)
+                        .expect("Type checking ensures that the struct has the member.")),
+                    // Unreachable because type checking ensures that only structs have members.
+                    value => unreachable!("Expected a struct, found `{value}`."),
+                },
+            },
+            AccessExpression::Tuple(access) => match self.evaluate_expression(&access.tuple)? {
+                Value::Tuple(mut elements) => Ok(elements.swap_remove(access.index.value())),
+                // Unreachable because type checking ensures that only tuples are accessed by position.
+                value => unreachable!("Expected a tuple, found `{value}`."),
+            },
+        }
+    }
+}
+
+/// Returns `true` if the two values are equal, ignoring where they were created.
+pub(crate) fn values_equal(left: &Value, right: &Value, span: Span) -> Result<bool> {
+    match (left, right) {
+        (Value::Struct(_, left), Value::Struct(_, right)) => {
+            for (name, left) in left.iter() {
+                if !values_equal(left, &right[name], span)? {
+                    return Ok(false);
+                }
+            }
+            Ok(true)
+        }
+        (Value::Tuple(left), Value::Tuple(right)) | (Value::Array(left), Value::Array(right)) => {
+            for (left, right) in left.iter().zip(right.iter()) {
+                if !values_equal(left, right, span)? {
+                    return Ok(false);
+                }
+            }
+            Ok(true)
+        }
+        // Group elements have several representations, so they cannot be compared by their text.
+        (Value::Group(_), _) => Err(InterpreterError::unsupported("comparing group elements", span).into()),
+        // The other values are canonical, so they are equal exactly when their text is.
+        (left, right) => Ok(left.to_string() == right.to_string()),
+    }
+}
+
+/// Evaluates a literal, writing field elements in their canonical form.
+fn evaluate_literal(literal: &Literal) -> Result<Value> {
+    let value = Value::try_from(literal).map_err(|_| InterpreterError::invalid_literal(literal, literal.span()))?;
+    match value {
+        Value::Field(digits, span) => Ok(from_field(to_field(&digits, span)?, span)),
+        value => Ok(value),
+    }
+}
+
+/// Evaluates a binary operation.
+fn evaluate_binary(left: Value, right: Value, op: BinaryOperation, span: Span) -> Result<Value> {
+    use BinaryOperation::*;
+
+    // Division by zero halts, rather than overflowing or wrapping.
+    if matches!(op, Div | DivWrapped | Mod | Rem | RemWrapped) && right.to_string() == "0" {
+        return Err(InterpreterError::division_by_zero(span).into());
+    }
+
+    match (op, &left, &right) {
+        (Eq, ..) => Ok(Value::Boolean(values_equal(&left, &right, span)?, span)),
+        (Neq, ..) => Ok(Value::Boolean(!values_equal(&left, &right, span)?, span)),
+        (_, Value::Field(left, _), Value::Field(right, _)) => {
+            let (left, right) = (to_field(left, span)?, to_field(right, span)?);
+            Ok(match op {
+                Add => from_field(left + right, span),
+                Sub => from_field(left - right, span),
+                Mul => from_field(left * right, span),
+                Div => from_field(left / right, span),
+                Pow => from_field(left.pow(right), span),
+                Gt => Value::Boolean(left > right, span),
+                Gte => Value::Boolean(left >= right, span),
+                Lt => Value::Boolean(left < right, span),
+                Lte => Value::Boolean(left <= right, span),
+                _ => return Err(InterpreterError::unsupported(format!("`{op}` on field elements"), span).into()),
+            })
+        }
+        _ if !left.is_supported_const_fold_type() || !right.is_supported_const_fold_type() => {
+            Err(InterpreterError::unsupported(format!("`{op}` on values of type `{}`", Type::from(&left)), span).into())
+        }
+        _ => match op {
+            Add => left.add(right, span),
+            AddWrapped => left.add_wrapped(right, span),
+            And | BitwiseAnd => left.bitand(right, span),
+            Div => left.div(right, span),
+            DivWrapped => left.div_wrapped(right, span),
+            Gte => left.ge(right, span),
+            Gt => left.gt(right, span),
+            Lte => left.le(right, span),
+            Lt => left.lt(right, span),
+            // Note that `Mod` is only defined on unsigned integers, for which it is the same as `Rem`.
+            Mod | Rem => left.rem(right, span),
+            RemWrapped => left.rem_wrapped(right, span),
+            Mul => left.mul(right, span),
+            MulWrapped => left.mul_wrapped(right, span),
+            Nand => left.bitand(right, span)?.not(span),
+            Nor => left.bitor(right, span)?.not(span),
+            Or | BitwiseOr => left.bitor(right, span),
+            Pow => left.pow(right, span),
+            PowWrapped => left.pow_wrapped(right, span),
+            Shl => left.shl(right, span),
+            ShlWrapped => left.shl_wrapped(right, span),
+            Shr => left.shr(right, span),
+            ShrWrapped => left.shr_wrapped(right, span),
+            Sub => left.sub(right, span),
+            SubWrapped => left.sub_wrapped(right, span),
+            Xor => left.xor(right, span),
+            Eq | Neq => unreachable!("Equality is evaluated above."),
+        },
+    }
+}
+
+/// Evaluates a unary operation.
+fn evaluate_unary(value: Value, op: UnaryOperation, span: Span) -> Result<Value> {
+    use UnaryOperation::*;
+
+    match (op, &value) {
+        (_, Value::Field(digits, _)) => {
+            let field = to_field(digits, span)?;
+            match op {
+                Double => Ok(from_field(field.double(), span)),
+                Inverse => match field.is_zero() {
+                    true => Err(InterpreterError::division_by_zero(span).into()),
+                    false => Ok(from_field(field.inverse().expect("Nonzero field elements are invertible."), span)),
+                },
+                Negate => Ok(from_field(-field, span)),
+                Square => Ok(from_field(field.square(), span)),
+                SquareRoot => match field.square_root() {
+                    Ok(root) => Ok(from_field(root, span)),
+                    Err(_) => Err(InterpreterError::unsupported("square roots of non-residues", span).into()),
+                },
+                _ => Err(InterpreterError::unsupported(format!("`{}` on field elements", op.as_str()), span).into()),
+            }
+        }
+        _ if !value.is_supported_const_fold_type() => Err(InterpreterError::unsupported(
+            format!("`{}` on values of type `{}`", op.as_str(), Type::from(&value)),
+            span,
+        )
+        .into()),
+        (Abs, _) => value.abs(span),
+        (AbsWrapped, _) => value.abs_wrapped(span),
+        (Negate, _) => value.neg(span),
+        (Not, _) => value.not(span),
+        _ => Err(InterpreterError::unsupported(format!("`{}` on values of type `{}`", op.as_str(), Type::from(&value)), span)
+            .into()),
+    }
+}
+
+/// Evaluates a cast between booleans, integers, and field elements.
+fn evaluate_cast(value: Value, type_: &Type, span: Span) -> Result<Value> {
+    let unsupported = || InterpreterError::unsupported(format!("casts from `{}` to `{type_}`", Type::from(&value)), span);
+    let out_of_range = || InterpreterError::cast_out_of_range(&value, type_, span);
+
+    match (&value, type_) {
+        _ if Type::from(&value) == *type_ => Ok(value.clone()),
+        (Value::Boolean(boolean, _), Type::Integer(integer_type)) => {
+            integer(*integer_type, &u8::from(*boolean).to_string(), span)
+        }
+        (Value::Boolean(boolean, _), Type::Field) => Ok(Value::Field(u8::from(*boolean).to_string(), span)),
+        // Integers and field elements can only be cast to booleans if they are zero or one.
+        (_, Type::Boolean) if value.is_supported_const_fold_type() || matches!(value, Value::Field(..)) => {
+            match value.to_string().as_str() {
+                "0" => Ok(Value::Boolean(false, span)),
+                "1" => Ok(Value::Boolean(true, span)),
+                _ => Err(out_of_range().into()),
+            }
+        }
+        // Casts between integers preserve the value, and fail if the value does not fit in the new type.
+        (_, Type::Integer(integer_type)) if value.is_supported_const_fold_type() => {
+            integer(*integer_type, &value.to_string(), span).map_err(|_| out_of_range().into())
+        }
+        // Casts from integers to field elements preserve the bits, so negative integers are cast in two's complement.
+        (_, Type::Field) if value.is_supported_const_fold_type() => {
+            let bits = integer_bits(&value).ok_or_else(unsupported)?;
+            Ok(Value::Field(bits.to_string(), span))
+        }
+        // Casts from field elements to integers also preserve the bits, and fail if they do not fit in the new type.
+        (Value::Field(digits, _), Type::Integer(integer_type)) => {
+            let bits = u128::from_str(digits).map_err(|_| out_of_range())?;
+            integer_from_bits(bits, *integer_type, span).ok_or_else(|| out_of_range().into())
+        }
+        _ => Err(unsupported().into()),
+    }
+}
+
+/// Returns the bits of an integer, reading negative integers in two's complement.
+fn integer_bits(value: &Value) -> Option<u128> {
+    Some(match value {
+        Value::I8(value, _) => *value as u8 as u128,
+        Value::I16(value, _) => *value as u16 as u128,
+        Value::I32(value, _) => *value as u32 as u128,
+        Value::I64(value, _) => *value as u64 as u128,
+        Value::I128(value, _) => *value as u128,
+        Value::U8(value, _) => *value as u128,
+        Value::U16(value, _) => *value as u128,
+        Value::U32(value, _) => *value as u128,
+        Value::U64(value, _) => *value as u128,
+        Value::U128(value, _) => *value,
+        _ => return None,
+    })
+}
+
+/// Returns the integer of the given type with the given bits, or `None` if they do not fit in the type.
+fn integer_from_bits(bits: u128, integer_type: IntegerType, span: Span) -> Option<Value> {
+    Some(match integer_type {
+        IntegerType::I8 => Value::I8(u8::try_from(bits).ok()? as i8, span),
+        IntegerType::I16 => Value::I16(u16::try_from(bits).ok()? as i16, span),
+        IntegerType::I32 => Value::I32(u32::try_from(bits).ok()? as i32, span),
+        IntegerType::I64 => Value::I64(u64::try_from(bits).ok()? as i64, span),
+        IntegerType::I128 => Value::I128(bits as i128, span),
+        IntegerType::U8 => Value::U8(u8::try_from(bits).ok()?, span),
+        IntegerType::U16 => Value::U16(u16::try_from(bits).ok()?, span),
+        IntegerType::U32 => Value::U32(u32::try_from(bits).ok()?, span),
+        IntegerType::U64 => Value::U64(u64::try_from(bits).ok()?, span),
+        IntegerType::U128 => Value::U128(bits, span),
+    })
+}
+
+/// Returns the field element with the given decimal digits.
+pub(crate) fn to_field(digits: &str, span: Span) -> Result<Field> {
+    Field::from_str(&format!("{}field", digits.replace('_', "")))
+        .map_err(|_| InterpreterError::invalid_literal(format!("{digits}field"), span).into())
+}
+
+/// Returns the value of a field element, written in its canonical form.
+pub(crate) fn from_field(field: Field, span: Span) -> Value {
+    Value::Field(field.to_string().trim_end_matches("field").to_string(), span)
+}
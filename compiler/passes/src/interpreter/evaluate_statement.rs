@@ -0,0 +1,180 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::evaluate_expression::values_equal;
+use crate::Interpreter;
+
+use leo_ast::{
+    AssertVariant,
+    Block,
+    ConsoleFunction,
+    Expression,
+    IntegerType,
+    IterationStatement,
+    Literal,
+    Statement,
+    Type,
+    Value,
+};
+use leo_errors::{InterpreterError, Result};
+use leo_span::Span;
+
+impl<'a> Interpreter<'a> {
+    /// Evaluates the statements of a block, returning the value of the first `return` statement that is reached.
+    pub(crate) fn evaluate_block(&mut self, block: &Block) -> Result<Option<Value>> {
+        for statement in block.statements.iter() {
+            if let Some(output) = self.evaluate_statement(statement)? {
+                return Ok(Some(output));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Evaluates a statement, returning the output of the function if the statement returns.
+    /// The variables that the statement defines stay in scope, so a REPL can evaluate one statement at a time.
+    pub fn evaluate_statement(&mut self, statement: &Statement) -> Result<Option<Value>> {
+        match statement {
+            Statement::Assert(input) => match &input.variant {
+                AssertVariant::Assert(condition) => self.evaluate_assert(condition, input.span)?,
+                AssertVariant::AssertEq(left, right) => self.evaluate_assert_eq(left, right, true, input.span)?,
+                AssertVariant::AssertNeq(left, right) => self.evaluate_assert_eq(left, right, false, input.span)?,
+            },
+            Statement::Assign(input) => {
+                let value = self.evaluate_expression(&input.value)?;
+                self.bind(&input.place, value)?;
+            }
+            Statement::Block(block) => return self.evaluate_block(block),
+            Statement::Conditional(input) => {
+                if self.evaluate_condition(&input.condition)? {
+                    return self.evaluate_block(&input.then);
+                } else if let Some(otherwise) = &input.otherwise {
+                    return self.evaluate_statement(otherwise);
+                }
+            }
+            Statement::Console(input) => match &input.function {
+                ConsoleFunction::Assert(condition) => self.evaluate_assert(condition, input.span)?,
+                ConsoleFunction::AssertEq(left, right) => self.evaluate_assert_eq(left, right, true, input.span)?,
+                ConsoleFunction::AssertNeq(left, right) => self.evaluate_assert_eq(left, right, false, input.span)?,
+            },
+            Statement::Const(input) => {
+                let value = self.evaluate_expression(&input.value)?;
+                self.variables.insert(input.place.name, value);
+            }
+            Statement::Definition(input) => {
+                let value = self.evaluate_expression(&input.value)?;
+                self.bind(&input.place, value)?;
+            }
+            Statement::Expression(input) => {
+                self.evaluate_expression(&input.expression)?;
+            }
+            Statement::Iteration(input) => return self.evaluate_iteration(input),
+            Statement::Return(input) => {
+                let output = self.evaluate_expression(&input.expression)?;
+                // The arguments to the finalize block are computed off-chain, so they can fail the transition,
+                // but the finalize block itself runs on-chain and is not evaluated.
+                for argument in input.finalize_arguments.iter().flatten() {
+                    self.evaluate_expression(argument)?;
+                }
+                return Ok(Some(output));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Evaluates a boolean condition.
+    pub(crate) fn evaluate_condition(&mut self, condition: &Expression) -> Result<bool> {
+        match self.evaluate_expression(condition)? {
+            Value::Boolean(condition, _) => Ok(condition),
+            // Unreachable because type checking ensures that conditions are booleans.
+            value => unreachable!("Expected a boolean condition, found `{value}`."),
+        }
+    }
+
+    /// Checks that an asserted condition holds.
+    fn evaluate_assert(&mut self, condition: &Expression, span: Span) -> Result<()> {
+        match self.evaluate_condition(condition)? {
+            true => Ok(()),
+            false => Err(InterpreterError::assertion_failed(span).into()),
+        }
+    }
+
+    /// Checks that two values are equal, or not equal if `equal` is `false`.
+    fn evaluate_assert_eq(&mut self, left: &Expression, right: &Expression, equal: bool, span: Span) -> Result<()> {
+        let left = self.evaluate_expression(left)?;
+        let right = self.evaluate_expression(right)?;
+        match (values_equal(&left, &right, span)?, equal) {
+            (true, true) | (false, false) => Ok(()),
+            (false, true) => Err(InterpreterError::assert_eq_failed(left, right, span).into()),
+            (true, false) => Err(InterpreterError::assert_neq_failed(left, right, span).into()),
+        }
+    }
+
+    /// Binds a value to a variable, or the elements of a tuple value to a tuple of variables.
+    fn bind(&mut self, place: &Expression, value: Value) -> Result<()> {
+        match (place, value) {
+            (Expression::Identifier(identifier), value) => {
+                self.variables.insert(identifier.name, value);
+            }
+            (Expression::Tuple(places), Value::Tuple(values)) => {
+                for (place, value) in places.elements.iter().zip(values) {
+                    self.bind(place, value)?;
+                }
+            }
+            // Unreachable because type checking ensures that only variables and tuples of variables are assigned to.
+            (place, value) => unreachable!("Cannot assign `{value}` to `{place}`."),
+        }
+        Ok(())
+    }
+
+    /// Evaluates a loop, returning the output of the function if its body returns.
+    fn evaluate_iteration(&mut self, input: &IterationStatement) -> Result<Option<Value>> {
+        let integer_type = match &input.type_ {
+            Type::Integer(integer_type) => *integer_type,
+            // Unreachable because type checking ensures that loop variables are integers.
+            type_ => unreachable!("Expected an integer loop variable, found `{type_}`."),
+        };
+        let one = integer(integer_type, "1", input.span)?;
+
+        let mut current = self.evaluate_expression(&input.start)?;
+        let stop = self.evaluate_expression(&input.stop)?;
+        loop {
+            let in_range = match input.inclusive {
+                true => current.clone().le(stop.clone(), input.span)?,
+                false => current.clone().lt(stop.clone(), input.span)?,
+            };
+            if !matches!(in_range, Value::Boolean(true, _)) {
+                return Ok(None);
+            }
+
+            self.variables.insert(input.variable.name, current.clone());
+            if let Some(output) = self.evaluate_block(&input.block)? {
+                return Ok(Some(output));
+            }
+
+            // Stop at the end of an inclusive range, so that a range ending at the largest value of its type does not overflow.
+            if input.inclusive && matches!(current.clone().ge(stop.clone(), input.span)?, Value::Boolean(true, _)) {
+                return Ok(None);
+            }
+            current = current.add(one.clone(), input.span)?;
+        }
+    }
+}
+
+/// Returns the integer of the given type with the given decimal digits.
+pub(crate) fn integer(integer_type: IntegerType, digits: &str, span: Span) -> Result<Value> {
+    let literal = Literal::Integer(integer_type, digits.to_string(), span, Default::default());
+    Value::try_from(&literal).map_err(|_| InterpreterError::invalid_literal(literal, span).into())
+}
@@ -0,0 +1,87 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{Function, ProgramScope, Value};
+use leo_errors::{InterpreterError, LeoError, LeoMessageCode, Result};
+use leo_span::{Span, Symbol};
+
+use indexmap::IndexMap;
+
+/// Evaluates the functions of a program over native values, without synthesizing a circuit.
+pub struct Interpreter<'a> {
+    /// The functions that can be called, by name.
+    pub(crate) functions: IndexMap<Symbol, &'a Function>,
+    /// The values of the program-level constants.
+    pub(crate) consts: IndexMap<Symbol, Value>,
+    /// The values of the variables of the function being evaluated.
+    /// Since Leo does not allow shadowing, a single map holds the variables of all of the function's scopes.
+    pub(crate) variables: IndexMap<Symbol, Value>,
+}
+
+impl<'a> Interpreter<'a> {
+    /// Returns an interpreter for the given functions.
+    pub fn new(functions: impl IntoIterator<Item = (Symbol, &'a Function)>) -> Self {
+        Self { functions: functions.into_iter().collect(), consts: IndexMap::new(), variables: IndexMap::new() }
+    }
+
+    /// Returns an interpreter for the functions of a program scope, with the values of its constants.
+    pub fn from_program_scope(program_scope: &'a ProgramScope) -> Result<Self> {
+        let mut interpreter = Self::new(program_scope.functions.iter().map(|(name, function)| (*name, function)));
+        for (name, declaration) in program_scope.consts.iter() {
+            let value = interpreter.evaluate_expression(&declaration.value)?;
+            interpreter.consts.insert(*name, value);
+        }
+        Ok(interpreter)
+    }
+
+    /// Calls the function `name` with the given arguments, returning its output.
+    /// The outputs of a function with several outputs are returned as a tuple, and a function without outputs returns the empty tuple.
+    pub fn call(&mut self, name: Symbol, arguments: Vec<Value>) -> Result<Value> {
+        let function = *self.functions.get(&name).ok_or_else(|| InterpreterError::unknown_function(name))?;
+        if function.input.len() != arguments.len() {
+            return Err(InterpreterError::wrong_number_of_inputs(name, function.input.len(), arguments.len()).into());
+        }
+
+        // Evaluate the body of the function with only its inputs in scope.
+        let variables = function.input.iter().map(|input| input.identifier().name).zip(arguments).collect();
+        let caller_variables = std::mem::replace(&mut self.variables, variables);
+        let output = self.evaluate_block(&function.block);
+        self.variables = caller_variables;
+
+        Ok(output?.unwrap_or_else(|| Value::Tuple(Vec::new())))
+    }
+
+    /// Returns the values of the variables that are in scope, outside of any call.
+    pub fn variables(&self) -> &IndexMap<Symbol, Value> {
+        &self.variables
+    }
+
+    /// Returns the variables that are in scope, outside of any call, so that they can be set before evaluating statements.
+    pub fn variables_mut(&mut self) -> &mut IndexMap<Symbol, Value> {
+        &mut self.variables
+    }
+
+    /// Returns `true` if `error` reports a construct that the interpreter cannot evaluate,
+    /// rather than a failure of the program being evaluated, such as a failed assertion or an overflow.
+    pub fn is_unsupported(error: &LeoError) -> bool {
+        match error {
+            LeoError::InterpreterError(error) => {
+                error.exit_code() == InterpreterError::unsupported("", Span::default()).exit_code()
+            }
+            _ => false,
+        }
+    }
+}
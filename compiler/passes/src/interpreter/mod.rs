@@ -0,0 +1,48 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The interpreter evaluates a Leo program directly over its AST, using native booleans, integers, and field elements.
+//! No circuit is synthesized, so evaluating a function is much faster than running it with snarkVM.
+//! This makes the interpreter useful for quick feedback when running tests,
+//! and for checking the outputs of the compiled program against the outputs of its source.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! transition main(a: u8, b: u8) -> u8 {
+//!     let c: u8 = 0u8;
+//!     for i: u8 in 0u8..4u8 {
+//!         c += a;
+//!     }
+//!     return c - b;
+//! }
+//! ```
+//!
+//! Calling `main` with `3u8` and `2u8` evaluates to `10u8`, and calling it with `1u8` and `5u8` fails,
+//! since the subtraction underflows, just as it does in the circuit.
+//!
+//! The interpreter only evaluates the part of a program that runs off-chain.
+//! Finalize blocks are not evaluated, and calls to other programs, core functions such as hashes,
+//! and group and scalar arithmetic are reported as unsupported.
+
+mod evaluate_expression;
+
+mod evaluate_statement;
+
+mod evaluator;
+pub use evaluator::*;
+
+mod values;
+pub use values::*;
@@ -0,0 +1,90 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+    evaluate_expression::{from_field, to_field},
+    evaluate_statement::integer,
+};
+
+use leo_ast::{Type, Value};
+use leo_errors::{InterpreterError, Result};
+use leo_span::Span;
+
+/// Parses an input written in Aleo syntax, e.g. `1u8`, `true`, or `[1u8, 2u8]`, as a value of the given type.
+pub fn parse_value(input: &str, type_: &Type, span: Span) -> Result<Value> {
+    let input = input.trim();
+    let invalid = || InterpreterError::invalid_input(input, type_, span);
+
+    match type_ {
+        Type::Address if input.starts_with("aleo1") => Ok(Value::Address(input.to_string(), span)),
+        Type::Boolean => match input {
+            "true" => Ok(Value::Boolean(true, span)),
+            "false" => Ok(Value::Boolean(false, span)),
+            _ => Err(invalid().into()),
+        },
+        Type::Field => {
+            let digits = input.strip_suffix("field").ok_or_else(invalid)?;
+            Ok(from_field(to_field(digits, span).map_err(|_| invalid())?, span))
+        }
+        Type::Integer(integer_type) => {
+            let digits = input.strip_suffix(&integer_type.to_string()).ok_or_else(invalid)?;
+            integer(*integer_type, digits, span).map_err(|_| invalid().into())
+        }
+        Type::Array(array_type) => {
+            let elements = input.strip_prefix('[').and_then(|input| input.strip_suffix(']')).ok_or_else(invalid)?;
+            let elements = split_elements(elements)
+                .into_iter()
+                .map(|element| parse_value(element, array_type.element_type(), span))
+                .collect::<Result<Vec<_>>>()?;
+            match elements.len() == array_type.length() {
+                true => Ok(Value::Array(elements)),
+                false => Err(invalid().into()),
+            }
+        }
+        _ => Err(InterpreterError::unsupported(format!("inputs of type `{type_}`"), span).into()),
+    }
+}
+
+/// Writes a value in Aleo syntax, e.g. `1u8` or `true`.
+/// Returns `None` for values that snarkVM writes across several lines, such as structs and arrays.
+pub fn format_value(value: &Value) -> Option<String> {
+    match value {
+        Value::Address(..) | Value::Boolean(..) => Some(value.to_string()),
+        Value::Field(digits, _) => Some(format!("{digits}field")),
+        value if value.is_supported_const_fold_type() => Some(format!("{value}{}", Type::from(value))),
+        _ => None,
+    }
+}
+
+/// Splits the elements of an array at its top-level commas.
+fn split_elements(elements: &str) -> Vec<&str> {
+    let mut depth = 0;
+    let mut start = 0;
+    let mut parts = Vec::new();
+    for (index, character) in elements.char_indices() {
+        match character {
+            '[' => depth += 1,
+            ']' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(&elements[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&elements[start..]);
+    parts
+}
@@ -23,6 +23,9 @@ pub use code_generation::*;
 pub mod common;
 pub use common::*;
 
+pub mod constant_inputs;
+pub use constant_inputs::*;
+
 pub mod dead_code_elimination;
 pub use dead_code_elimination::*;
 
@@ -35,6 +38,12 @@ pub use flattening::*;
 pub mod function_inlining;
 pub use function_inlining::*;
 
+pub mod interpreter;
+pub use interpreter::*;
+
+pub mod linting;
+pub use linting::*;
+
 pub mod loop_unrolling;
 pub use self::loop_unrolling::*;
 
@@ -0,0 +1,87 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Lint, Linter};
+
+use leo_ast::*;
+use leo_errors::LinterWarning;
+
+impl<'a> ExpressionVisitor<'a> for Linter<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_access(&mut self, input: &'a AccessExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        match input {
+            AccessExpression::Array(array) => {
+                self.visit_expression(&array.array, additional);
+                self.visit_expression(&array.index, additional);
+            }
+            AccessExpression::AssociatedFunction(function) => {
+                // Check for hashes of values that can be found by hashing every possible value.
+                if function.name.name.to_string().starts_with("hash") {
+                    for argument in function.arguments.iter() {
+                        if let Some(type_) = self.type_table.get(&argument.id()).filter(has_small_domain) {
+                            self.emit_lint(
+                                Lint::SmallDomainHash,
+                                LinterWarning::small_domain_hash(type_, function.span),
+                            );
+                        }
+                    }
+                }
+                function.arguments.iter().for_each(|argument| self.visit_expression(argument, additional));
+            }
+            AccessExpression::Member(member) => {
+                self.visit_expression(&member.inner, additional);
+            }
+            AccessExpression::Tuple(tuple) => {
+                self.visit_expression(&tuple.tuple, additional);
+            }
+            _ => {}
+        }
+    }
+
+    fn visit_binary(&mut self, input: &'a BinaryExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        if matches!(input.op, BinaryOperation::Lt | BinaryOperation::Lte | BinaryOperation::Gt | BinaryOperation::Gte)
+            && self.type_table.get(&input.left.id()) == Some(Type::Field)
+        {
+            self.emit_lint(Lint::FieldComparison, LinterWarning::field_comparison(input.span));
+        }
+        self.visit_expression(&input.left, additional);
+        self.visit_expression(&input.right, additional);
+    }
+
+    fn visit_identifier(&mut self, input: &'a Identifier, _additional: &Self::AdditionalInput) -> Self::Output {
+        self.unused_inputs.shift_remove(&input.name);
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        for member in input.members.iter() {
+            match &member.expression {
+                Some(expression) => self.visit_expression(expression, additional),
+                // A member without an expression, e.g. `Foo { x }`, is initialized with the variable of the same name.
+                None => self.visit_identifier(&member.identifier, additional),
+            }
+        }
+    }
+}
+
+/// Returns whether a type has few enough values that all of them can be hashed.
+fn has_small_domain(type_: &Type) -> bool {
+    matches!(
+        type_,
+        Type::Boolean | Type::Integer(IntegerType::U8 | IntegerType::I8 | IntegerType::U16 | IntegerType::I16)
+    )
+}
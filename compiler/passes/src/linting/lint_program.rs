@@ -0,0 +1,60 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Lint, Linter};
+
+use leo_ast::*;
+use leo_errors::LinterWarning;
+
+impl<'a> ProgramVisitor<'a> for Linter<'a> {
+    fn visit_import(&mut self, _input: &'a Program) {
+        // Imported programs are linted in their own packages.
+    }
+
+    fn visit_function(&mut self, input: &'a Function) {
+        // Track the private inputs of transitions, which the prover chooses.
+        // Records are skipped, since consuming a record constrains it even if its fields are never read.
+        if input.variant == Variant::Transition {
+            self.unused_inputs = input
+                .input
+                .iter()
+                .filter_map(|input| match input {
+                    Input::Internal(input) if matches!(input.mode, Mode::None | Mode::Private) => {
+                        let is_record = match &input.type_ {
+                            Type::Identifier(identifier) => self
+                                .symbol_table
+                                .lookup_struct(identifier.name)
+                                .map_or(false, |struct_| struct_.is_record),
+                            _ => false,
+                        };
+                        (!is_record).then_some((input.identifier.name, input.identifier.span))
+                    }
+                    _ => None,
+                })
+                .collect();
+        }
+
+        self.visit_block(&input.block);
+
+        for (name, span) in std::mem::take(&mut self.unused_inputs) {
+            self.emit_lint(Lint::UnconstrainedWitness, LinterWarning::unconstrained_witness(name, span));
+        }
+
+        if let Some(finalize) = &input.finalize {
+            self.visit_block(&finalize.block);
+        }
+    }
+}
@@ -0,0 +1,79 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Lint, Linter};
+
+use leo_ast::*;
+use leo_errors::LinterWarning;
+use leo_span::Span;
+
+impl<'a> StatementVisitor<'a> for Linter<'a> {
+    fn visit_assert(&mut self, input: &'a AssertStatement) {
+        match &input.variant {
+            AssertVariant::Assert(expr) => {
+                // The type checker already warns about `assert(true)`.
+                if !matches!(expr, Expression::Literal(Literal::Boolean(true, ..))) {
+                    self.check_constant_assertion(&[expr], input.span);
+                }
+                self.visit_expression(expr, &Default::default());
+            }
+            AssertVariant::AssertEq(left, right) | AssertVariant::AssertNeq(left, right) => {
+                self.check_constant_assertion(&[left, right], input.span);
+                self.visit_expression(left, &Default::default());
+                self.visit_expression(right, &Default::default());
+            }
+        }
+    }
+
+    fn visit_console(&mut self, input: &'a ConsoleStatement) {
+        match &input.function {
+            ConsoleFunction::Assert(expr) => {
+                self.check_constant_assertion(&[expr], input.span);
+                self.visit_expression(expr, &Default::default());
+            }
+            ConsoleFunction::AssertEq(left, right) | ConsoleFunction::AssertNeq(left, right) => {
+                self.check_constant_assertion(&[left, right], input.span);
+                self.visit_expression(left, &Default::default());
+                self.visit_expression(right, &Default::default());
+            }
+        }
+    }
+}
+
+impl<'a> Linter<'a> {
+    /// Emits the `constant_assertion` lint if the operands of an assertion only involve literals.
+    fn check_constant_assertion(&mut self, operands: &[&Expression], span: Span) {
+        if operands.iter().all(|operand| is_constant(operand)) {
+            self.emit_lint(Lint::ConstantAssertion, LinterWarning::constant_assertion(span));
+        }
+    }
+}
+
+/// Returns whether an expression only involves literals, so that its value does not depend on the inputs.
+fn is_constant(expr: &Expression) -> bool {
+    match expr {
+        Expression::Literal(_) | Expression::Unit(_) => true,
+        Expression::Binary(binary) => is_constant(&binary.left) && is_constant(&binary.right),
+        Expression::Cast(cast) => is_constant(&cast.expression),
+        Expression::Ternary(ternary) => {
+            is_constant(&ternary.condition) && is_constant(&ternary.if_true) && is_constant(&ternary.if_false)
+        }
+        Expression::Array(array) => array.elements.iter().all(is_constant),
+        Expression::Tuple(tuple) => tuple.elements.iter().all(is_constant),
+        Expression::Unary(unary) => is_constant(&unary.receiver),
+        _ => false,
+    }
+}
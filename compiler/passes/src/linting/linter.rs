@@ -0,0 +1,61 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Lint, LintConfig, LintLevel, SymbolTable, TypeTable};
+
+use leo_errors::{LinterWarning, emitter::Handler};
+use leo_span::{Span, Symbol};
+
+use indexmap::IndexMap;
+
+pub struct Linter<'a> {
+    /// The error handler, which the lints are emitted to.
+    pub(crate) handler: &'a Handler,
+    /// The symbol table of the program.
+    pub(crate) symbol_table: &'a SymbolTable,
+    /// The types of the expressions of the program.
+    pub(crate) type_table: &'a TypeTable,
+    /// The level of each lint.
+    pub(crate) config: &'a LintConfig,
+    /// The private inputs of the current transition that have not been used yet, and their spans.
+    pub(crate) unused_inputs: IndexMap<Symbol, Span>,
+    /// The number of lints that were found at the `deny` level.
+    pub(crate) denied: usize,
+}
+
+impl<'a> Linter<'a> {
+    /// Initializes a new `Linter`.
+    pub fn new(
+        handler: &'a Handler,
+        symbol_table: &'a SymbolTable,
+        type_table: &'a TypeTable,
+        config: &'a LintConfig,
+    ) -> Self {
+        Self { handler, symbol_table, type_table, config, unused_inputs: Default::default(), denied: 0 }
+    }
+
+    /// Emits a lint, unless it is allowed.
+    pub(crate) fn emit_lint(&mut self, lint: Lint, warning: LinterWarning) {
+        match self.config.level(lint) {
+            LintLevel::Allow => {}
+            LintLevel::Warn => self.handler.emit_warning(warning.into()),
+            LintLevel::Deny => {
+                self.denied += 1;
+                self.handler.emit_warning(warning.into());
+            }
+        }
+    }
+}
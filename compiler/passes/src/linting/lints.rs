@@ -0,0 +1,112 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use indexmap::IndexMap;
+use std::{fmt, str::FromStr};
+
+/// The lints that the linter checks for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Lint {
+    /// A private input of a transition that is never used, so that it does not constrain the proof.
+    UnconstrainedWitness,
+    /// An ordering comparison of `field` elements.
+    FieldComparison,
+    /// An assertion that only involves constants.
+    ConstantAssertion,
+    /// A hash of a value with few possible values, which can be inverted by brute force.
+    SmallDomainHash,
+}
+
+impl Lint {
+    /// All of the lints.
+    pub const ALL: [Lint; 4] =
+        [Lint::UnconstrainedWitness, Lint::FieldComparison, Lint::ConstantAssertion, Lint::SmallDomainHash];
+}
+
+impl fmt::Display for Lint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::UnconstrainedWitness => write!(f, "unconstrained_witness"),
+            Self::FieldComparison => write!(f, "field_comparison"),
+            Self::ConstantAssertion => write!(f, "constant_assertion"),
+            Self::SmallDomainHash => write!(f, "small_domain_hash"),
+        }
+    }
+}
+
+impl FromStr for Lint {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL.into_iter().find(|lint| lint.to_string() == s).ok_or_else(|| {
+            let names = Self::ALL.iter().map(Lint::to_string).collect::<Vec<_>>().join(", ");
+            format!("unknown lint `{s}`, expected one of: {names}")
+        })
+    }
+}
+
+/// How a lint is reported.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LintLevel {
+    /// The lint is not checked.
+    Allow,
+    /// The lint is reported as a warning.
+    #[default]
+    Warn,
+    /// The lint is reported as a warning, and fails the lint run.
+    Deny,
+}
+
+impl fmt::Display for LintLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Allow => write!(f, "allow"),
+            Self::Warn => write!(f, "warn"),
+            Self::Deny => write!(f, "deny"),
+        }
+    }
+}
+
+impl FromStr for LintLevel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "allow" => Ok(Self::Allow),
+            "warn" => Ok(Self::Warn),
+            "deny" => Ok(Self::Deny),
+            _ => Err(format!("unknown lint level `{s}`, expected one of: allow, warn, deny")),
+        }
+    }
+}
+
+/// The level of each lint. Lints that are not configured are reported as warnings.
+#[derive(Clone, Debug, Default)]
+pub struct LintConfig {
+    levels: IndexMap<Lint, LintLevel>,
+}
+
+impl LintConfig {
+    /// Sets the level of a lint.
+    pub fn set(&mut self, lint: Lint, level: LintLevel) {
+        self.levels.insert(lint, level);
+    }
+
+    /// Returns the level of a lint.
+    pub fn level(&self, lint: Lint) -> LintLevel {
+        self.levels.get(&lint).copied().unwrap_or_default()
+    }
+}
@@ -0,0 +1,127 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Linter pass traverses the type checked AST and warns about code that compiles but is likely unsound
+//! in a zero-knowledge program, e.g. a private input that the proof does not constrain.
+//! Each lint can be allowed, reported as a warning, or denied, as set in a `LintConfig`.
+//! The pass is run after the Type Checking pass, and does not modify the AST.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! transition main(secret: field, salt: field, guess: u8) -> field {
+//!     assert(secret < 100field);
+//!     return BHP256::hash_to_field(guess);
+//! }
+//! ```
+//!
+//! The linter reports that `salt` is never used, that `secret < 100field` orders `field` elements,
+//! and that the hash of `guess` can be inverted by hashing each of the 256 values of a `u8`.
+
+mod lint_expression;
+
+mod lint_program;
+
+mod lint_statement;
+
+pub mod linter;
+pub use linter::*;
+
+pub mod lints;
+pub use lints::*;
+
+use crate::{Pass, SymbolTable, TypeTable};
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::{Result, emitter::Handler};
+
+impl<'a> Pass for Linter<'a> {
+    type Input = (&'a Ast, &'a Handler, &'a SymbolTable, &'a TypeTable, &'a LintConfig);
+    /// The number of lints that were found at the `deny` level.
+    type Output = Result<usize>;
+
+    fn do_pass((ast, handler, st, tt, config): Self::Input) -> Self::Output {
+        let mut visitor = Linter::new(handler, st, tt, config);
+        visitor.visit_program(ast.as_repr());
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(visitor.denied)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{SymbolTableCreator, TypeChecker};
+
+    use leo_ast::NodeBuilder;
+    use leo_span::{source_map::FileName, symbol::create_session_if_not_set_then};
+
+    const PROGRAM: &str = "
+program test.aleo {
+    record Token {
+        owner: address,
+        amount: u64,
+    }
+
+    transition main(a: field, b: field, unused: u8, token: Token, public p: u8) -> bool {
+        assert_eq(1u8 + 1u8, 2u8);
+        let h: field = BHP256::hash_to_field(p);
+        return a < b;
+    }
+}
+";
+
+    /// Lints `PROGRAM`, and returns the codes of the lints that were found and the number that were denied.
+    fn lint(config: &LintConfig) -> (Vec<String>, usize) {
+        create_session_if_not_set_then(|s| {
+            let (handler, buf) = Handler::new_with_buf();
+            let source_file = s.source_map.new_source(PROGRAM, FileName::Custom("test".into()));
+            let ast = leo_parser::parse_ast(&handler, &NodeBuilder::default(), &source_file.src, source_file.start_pos)
+                .unwrap();
+            let type_table = TypeTable::default();
+            let symbol_table = SymbolTableCreator::do_pass((&ast, &handler)).unwrap();
+            let (symbol_table, ..) = TypeChecker::do_pass((&ast, &handler, symbol_table, &type_table)).unwrap();
+            let denied = Linter::do_pass((&ast, &handler, &symbol_table, &type_table, config)).unwrap();
+
+            let codes = buf
+                .extract_warnings()
+                .into_inner()
+                .iter()
+                .map(|warning| warning.warning_code())
+                .filter(|code| code.contains("LIN"))
+                .collect();
+            (codes, denied)
+        })
+    }
+
+    #[test]
+    fn test_lints() {
+        let (codes, denied) = lint(&LintConfig::default());
+        assert_eq!(codes, ["WLIN0374002", "WLIN0374003", "WLIN0374001", "WLIN0374000"]);
+        assert_eq!(denied, 0);
+    }
+
+    #[test]
+    fn test_lint_levels() {
+        let mut config = LintConfig::default();
+        config.set(Lint::UnconstrainedWitness, LintLevel::Deny);
+        config.set(Lint::SmallDomainHash, LintLevel::Allow);
+
+        let (codes, denied) = lint(&config);
+        assert_eq!(codes, ["WLIN0374002", "WLIN0374001", "WLIN0374000"]);
+        assert_eq!(denied, 1);
+    }
+}
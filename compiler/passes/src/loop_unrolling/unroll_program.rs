@@ -51,7 +51,9 @@ impl ProgramReconstructor for Unroller<'_> {
         let finalize = function.finalize.map(|finalize| {
             let previous_scope_index = self.enter_scope(self.scope_index);
 
+            self.is_finalize = true;
             let block = self.reconstruct_block(finalize.block).0;
+            self.is_finalize = false;
 
             self.exit_scope(previous_scope_index);
 
@@ -74,6 +74,36 @@ impl StatementReconstructor for Unroller<'_> {
         )
     }
 
+    fn reconstruct_conditional(&mut self, input: ConditionalStatement) -> (Statement, Self::AdditionalOutput) {
+        // Reconstruct the condition to allow for constant propagation.
+        let condition = self.reconstruct_expression(input.condition).0;
+
+        // Both branches are reconstructed, even if one of them is pruned, so that the scope indices stay in sync with the symbol table.
+        let then = self.reconstruct_block(input.then).0;
+        let otherwise = input.otherwise.map(|statement| self.reconstruct_statement(*statement).0);
+
+        match condition {
+            // If the condition is a constant, then only keep the branch that is taken.
+            // Finalize blocks are executed on-chain rather than proven, and snarkVM requires them to contain at least one command, so they are left as is.
+            Literal(leo_ast::Literal::Boolean(true, ..)) if !self.is_finalize => {
+                (Statement::Block(then), Default::default())
+            }
+            Literal(leo_ast::Literal::Boolean(false, ..)) if !self.is_finalize => {
+                (otherwise.unwrap_or_else(|| Statement::dummy(input.span, input.id)), Default::default())
+            }
+            _ => (
+                Statement::Conditional(ConditionalStatement {
+                    condition,
+                    then,
+                    otherwise: otherwise.map(Box::new),
+                    span: input.span,
+                    id: input.id,
+                }),
+                Default::default(),
+            ),
+        }
+    }
+
     fn reconstruct_definition(&mut self, input: DefinitionStatement) -> (Statement, Self::AdditionalOutput) {
         // Helper function to add  variables to symbol table
         let insert_variable = |symbol: Symbol, type_: Type, span: Span| {
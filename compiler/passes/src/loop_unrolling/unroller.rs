@@ -54,6 +54,8 @@ pub struct Unroller<'a> {
     pub(crate) node_builder: &'a NodeBuilder,
     /// Are we in the midst of unrolling a loop?
     pub(crate) is_unrolling: bool,
+    /// Are we traversing a finalize block?
+    pub(crate) is_finalize: bool,
 }
 
 impl<'a> Unroller<'a> {
@@ -71,6 +73,7 @@ impl<'a> Unroller<'a> {
             handler,
             node_builder,
             is_unrolling: false,
+            is_finalize: false,
         }
     }
 
@@ -17,7 +17,7 @@
 use crate::TypeChecker;
 
 use leo_ast::*;
-use leo_errors::{emitter::Handler, TypeCheckerError};
+use leo_errors::{emitter::Handler, TypeCheckerError, TypeCheckerWarning};
 use leo_span::{sym, Span};
 
 use itertools::Itertools;
@@ -307,6 +307,11 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
     }
 
     fn visit_binary(&mut self, input: &'a BinaryExpression, destination: &Self::AdditionalInput) -> Self::Output {
+        // Warn about comparisons whose result does not depend on their operands.
+        if let Some(value) = Self::constant_comparison(input) {
+            self.emit_warning(TypeCheckerWarning::constant_comparison(value, input.span()));
+        }
+
         match input.op {
             BinaryOperation::And | BinaryOperation::Or | BinaryOperation::Nand | BinaryOperation::Nor => {
                 // Only boolean types.
@@ -315,7 +320,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 let t2 = self.visit_expression(&input.right, destination);
 
                 // Check that both operands have the same type.
-                self.check_eq_types(&t1, &t2, input.span());
+                self.check_eq_types(&t1, &t2, (input.left.span(), input.right.span()), input.span());
 
                 return_incorrect_type(t1, t2, destination)
             }
@@ -326,7 +331,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 let t2 = self.visit_expression(&input.right, destination);
 
                 // Check that both operands have the same type.
-                self.check_eq_types(&t1, &t2, input.span());
+                self.check_eq_types(&t1, &t2, (input.left.span(), input.right.span()), input.span());
 
                 return_incorrect_type(t1, t2, destination)
             }
@@ -337,7 +342,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 let t2 = self.visit_expression(&input.right, destination);
 
                 // Check that both operands have the same type.
-                self.check_eq_types(&t1, &t2, input.span());
+                self.check_eq_types(&t1, &t2, (input.left.span(), input.right.span()), input.span());
 
                 return_incorrect_type(t1, t2, destination)
             }
@@ -348,7 +353,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 let t2 = self.visit_expression(&input.right, destination);
 
                 // Check that both operands have the same type.
-                self.check_eq_types(&t1, &t2, input.span());
+                self.check_eq_types(&t1, &t2, (input.left.span(), input.right.span()), input.span());
 
                 return_incorrect_type(t1, t2, destination)
             }
@@ -382,7 +387,20 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                     (Some(Type::Integer(integer_type)), _, other, other_span)
                     | (other, other_span, Some(Type::Integer(integer_type)), _) => {
                         // Other type must be the same integer type.
-                        self.assert_type(&other, &Type::Integer(integer_type), other_span);
+                        match other {
+                            Some(Type::Integer(other_type)) if other_type != integer_type => {
+                                // The integer type is the type of the left operand, since the first pattern matches.
+                                self.emit_err(
+                                    TypeCheckerError::mismatched_integer_types(
+                                        integer_type,
+                                        other_type,
+                                        input.left.span(),
+                                    )
+                                    .with_related_span(other_span),
+                                );
+                            }
+                            _ => self.assert_type(&other, &Type::Integer(integer_type), other_span),
+                        }
 
                         // Operation returns the same integer type.
                         self.assert_type(destination, &Type::Integer(integer_type), input.span());
@@ -416,7 +434,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 let t2 = self.visit_expression(&input.right, destination);
 
                 // Check that both operands have the same type.
-                self.check_eq_types(&t1, &t2, input.span());
+                self.check_eq_types(&t1, &t2, (input.left.span(), input.right.span()), input.span());
 
                 return_incorrect_type(t1, t2, destination)
             }
@@ -428,7 +446,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 let t2 = self.visit_expression(&input.right, destination);
 
                 // Check that both operands have the same type.
-                self.check_eq_types(&t1, &t2, input.span());
+                self.check_eq_types(&t1, &t2, (input.left.span(), input.right.span()), input.span());
 
                 return_incorrect_type(t1, t2, destination)
             }
@@ -440,7 +458,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 let t2 = self.visit_expression(&input.right, destination);
 
                 // Check that both operands have the same type.
-                self.check_eq_types(&t1, &t2, input.span());
+                self.check_eq_types(&t1, &t2, (input.left.span(), input.right.span()), input.span());
 
                 return_incorrect_type(t1, t2, destination)
             }
@@ -495,7 +513,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 let t2 = self.visit_expression(&input.right, &None);
 
                 // Check that the types of the operands are equal.
-                self.check_eq_types(&t1, &t2, input.span());
+                self.check_eq_types(&t1, &t2, (input.left.span(), input.right.span()), input.span());
 
                 // Operation returns a boolean.
                 self.assert_bool_type(destination, input.span());
@@ -519,7 +537,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 }
 
                 // Check that the types of the operands are equal.
-                self.check_eq_types(&t1, &t2, input.span());
+                self.check_eq_types(&t1, &t2, (input.left.span(), input.right.span()), input.span());
 
                 // Operation returns a boolean.
                 self.assert_bool_type(destination, input.span());
@@ -536,7 +554,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 let t2 = self.visit_expression(&input.right, destination);
 
                 // Check that both operands have the same type.
-                self.check_eq_types(&t1, &t2, input.span());
+                self.check_eq_types(&t1, &t2, (input.left.span(), input.right.span()), input.span());
 
                 return_incorrect_type(t1, t2, destination)
             }
@@ -568,6 +586,9 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 // Do not move it into the `if let Some(func) ...` block or it will keep `self.symbol_table_creation` alive for the entire block and will be very memory inefficient!
                 let func = self.symbol_table.borrow().lookup_fn_symbol(ident.name).cloned();
 
+                // Calling a function defined in an imported program is a use of that program.
+                self.use_imported_symbol(ident.name);
+
                 if let Some(func) = func {
                     // Check that the call is valid.
                     // Note that this unwrap is safe since we always set the variant before traversing the body of the function.
@@ -639,6 +660,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
     }
 
     fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        self.use_imported_symbol(input.name.name);
         let struct_ = self.symbol_table.borrow().lookup_struct(input.name.name).cloned();
         if let Some(struct_) = struct_ {
             // Check struct type name.
@@ -685,6 +707,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
     }
 
     fn visit_identifier(&mut self, input: &'a Identifier, expected: &Self::AdditionalInput) -> Self::Output {
+        self.use_variable(input.name);
         if let Some(var) = self.symbol_table.borrow().lookup_variable(input.name) {
             Some(self.assert_and_return_type(var.type_.clone(), expected, input.span()))
         } else {
@@ -17,12 +17,12 @@
 use crate::{DiGraphError, TypeChecker, VariableSymbol, VariableType};
 
 use leo_ast::*;
-use leo_errors::TypeCheckerError;
+use leo_errors::{AstError, TypeCheckerError, TypeCheckerWarning};
 use leo_span::sym;
 
 use snarkvm_console::network::{Network, Testnet3};
 
-use std::collections::HashSet;
+use std::collections::HashMap;
 
 // TODO: Cleanup logic for tuples.
 
@@ -37,6 +37,15 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
             }
             // Otherwise, typecheck the imported programs.
             false => {
+                // Record the structs and functions defined in each imported program, so that unused imports can be detected.
+                for (name, (program, _)) in input.imports.iter() {
+                    for scope in program.program_scopes.values() {
+                        let symbols = scope.structs.iter().map(|(symbol, _)| symbol);
+                        let symbols = symbols.chain(scope.functions.iter().map(|(symbol, _)| symbol));
+                        self.imported_symbols.extend(symbols.map(|symbol| (*symbol, *name)));
+                    }
+                }
+
                 // Set `self.is_imported`.
                 let previous_is_imported = core::mem::replace(&mut self.is_imported, true);
 
@@ -50,6 +59,15 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
 
         // Typecheck the program scopes.
         input.program_scopes.values().for_each(|scope| self.visit_program_scope(scope));
+
+        // Warn about the imported programs that are never used.
+        if !self.is_imported {
+            input
+                .imports
+                .iter()
+                .filter(|(name, _)| !self.used_imports.borrow().contains(*name))
+                .for_each(|(name, (_, span))| self.emit_warning(TypeCheckerWarning::unused_import(name, *span)));
+        }
     }
 
     fn visit_program_scope(&mut self, input: &'a ProgramScope) {
@@ -105,19 +123,25 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
 
     fn visit_struct(&mut self, input: &'a Struct) {
         // Check for conflicting struct/record member names.
-        let mut used = HashSet::new();
-        // TODO: Better span to target duplicate member.
-        if !input.members.iter().all(|Member { identifier, type_, span, .. }| {
+        let mut used = HashMap::new();
+        input.members.iter().for_each(|Member { identifier, type_, span, .. }| {
             // Check that the member types are defined.
             self.assert_type_is_valid(type_, *span);
-            used.insert(identifier.name)
-        }) {
-            self.emit_err(if input.is_record {
-                TypeCheckerError::duplicate_record_variable(input.name(), input.span())
-            } else {
-                TypeCheckerError::duplicate_struct_member(input.name(), input.span())
-            });
-        }
+            // Report every redefinition of a member at the span of the redefinition and of the first definition.
+            match used.get(&identifier.name) {
+                Some(first) => self.emit_err(
+                    if input.is_record {
+                        TypeCheckerError::duplicate_record_variable(input.name(), identifier.name, *span)
+                    } else {
+                        TypeCheckerError::duplicate_struct_member(input.name(), identifier.name, *span)
+                    }
+                    .with_related_span(*first),
+                ),
+                None => {
+                    used.insert(identifier.name, *span);
+                }
+            }
+        });
 
         // For records, enforce presence of the `owner: Address` member.
         if input.is_record {
@@ -205,10 +229,29 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
 
     fn visit_function(&mut self, function: &'a Function) {
         // Check that the function's annotations are valid.
-        // Note that Leo does not natively support any specific annotations.
+        // Note that `@test` and `@max_constraints` are the only annotations that Leo supports,
+        // and only on transitions.
         for annotation in function.annotations.iter() {
-            // TODO: Change to compiler warning.
-            self.emit_err(TypeCheckerError::unknown_annotation(annotation, annotation.span))
+            match annotation.identifier.name {
+                sym::test if function.variant == Variant::Transition => annotation
+                    .arguments
+                    .iter()
+                    .filter(|argument| {
+                        !matches!(argument, AnnotationArgument::Identifier(identifier) if identifier.name == sym::should_fail)
+                    })
+                    .for_each(|argument| self.emit_err(TypeCheckerError::unknown_test_argument(argument, argument.span()))),
+                sym::test => self.emit_err(TypeCheckerError::test_annotation_on_non_transition(annotation.span)),
+                sym::max_constraints if function.variant == Variant::Transition => {
+                    if annotation.arguments.len() != 1 || function.max_constraints().is_none() {
+                        self.emit_err(TypeCheckerError::invalid_max_constraints_argument(annotation.span))
+                    }
+                }
+                sym::max_constraints => {
+                    self.emit_err(TypeCheckerError::max_constraints_annotation_on_non_transition(annotation.span))
+                }
+                // TODO: Change to compiler warning.
+                _ => self.emit_err(TypeCheckerError::unknown_annotation(annotation, annotation.span)),
+            }
         }
 
         self.variant = Some(function.variant);
@@ -222,6 +265,7 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
 
         // The function's body does not have a return statement.
         self.has_return = false;
+        self.missing_return = None;
 
         // The function's body does not have a finalize statement.
         self.has_finalize = false;
@@ -234,6 +278,11 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
 
         // Type check the function's parameters.
         function.input.iter().for_each(|input_var| {
+            // Taking a record defined in an imported program as input is a use of that program.
+            if let Input::External(external) = input_var {
+                self.use_import(external.program_name.name);
+            }
+
             // Check that the type of input parameter is defined.
             self.assert_type_is_valid(&input_var.type_(), input_var.span());
             // Check that the type of the input parameter is not a tuple.
@@ -262,7 +311,15 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
                     declaration: VariableType::Input(input_var.mode()),
                 })
             {
-                self.handler.emit_err(err);
+                // A repeated parameter name is reported at both parameters.
+                let identifier = input_var.identifier();
+                let first = function.input.iter().map(|input| input.identifier()).find(|first| first.name == identifier.name);
+                match first {
+                    Some(first) if first.span != identifier.span => self.handler.emit_err(
+                        AstError::shadowed_variable(identifier.name, identifier.span).with_related_span(first.span),
+                    ),
+                    _ => self.handler.emit_err(err),
+                }
             }
         });
 
@@ -271,6 +328,9 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
         function.output.iter().for_each(|output| {
             match output {
                 Output::External(external) => {
+                    // Outputting a record defined in an imported program is a use of that program.
+                    self.use_import(external.program_name.name);
+
                     // If the function is not a transition function, then it cannot output a record.
                     // Note that an external output must always be a record.
                     if !matches!(function.variant, Variant::Transition) {
@@ -308,7 +368,10 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
 
         // If the function has a return type, then check that it has a return.
         if function.output_type != Type::Unit && !self.has_return {
-            self.emit_err(TypeCheckerError::missing_return(function.span));
+            match self.missing_return.take() {
+                Some(span) => self.emit_err(TypeCheckerError::missing_return_in_branch(span)),
+                None => self.emit_err(TypeCheckerError::missing_return(function.span)),
+            }
         }
 
         // If the function has a finalize block, then check that it has at least one finalize statement.
@@ -324,6 +387,7 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
             self.is_finalize = true;
             // The function's finalize block does not have a return statement.
             self.has_return = false;
+            self.missing_return = None;
             // The function;s finalize block does not have a finalize statement.
             self.has_finalize = false;
 
@@ -421,7 +485,10 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
 
             // If the function has a return type, then check that it has a return.
             if finalize.output_type != Type::Unit && !self.has_return {
-                self.emit_err(TypeCheckerError::missing_return(finalize.span));
+                match self.missing_return.take() {
+                    Some(span) => self.emit_err(TypeCheckerError::missing_return_in_branch(span)),
+                    None => self.emit_err(TypeCheckerError::missing_return(finalize.span)),
+                }
             }
 
             // Exit the scope for the finalize block.
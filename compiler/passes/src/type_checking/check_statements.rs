@@ -18,7 +18,7 @@ use crate::{TypeChecker, VariableSymbol, VariableType};
 use itertools::Itertools;
 
 use leo_ast::*;
-use leo_errors::TypeCheckerError;
+use leo_errors::{TypeCheckerError, TypeCheckerWarning};
 use leo_span::{Span, Symbol};
 
 impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
@@ -48,13 +48,27 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
             AssertVariant::Assert(expr) => {
                 let type_ = self.visit_expression(expr, &Some(Type::Boolean));
                 self.assert_bool_type(&type_, expr.span());
+
+                // Warn about asserting `true`.
+                if let Expression::Literal(Literal::Boolean(true, ..)) = expr {
+                    self.emit_warning(TypeCheckerWarning::trivial_assertion(input.span()));
+                }
             }
             AssertVariant::AssertEq(left, right) | AssertVariant::AssertNeq(left, right) => {
+                // Warn about asserting that a variable is equal to itself.
+                if let (AssertVariant::AssertEq(..), Expression::Identifier(left), Expression::Identifier(right)) =
+                    (&input.variant, left, right)
+                {
+                    if left.name == right.name {
+                        self.emit_warning(TypeCheckerWarning::trivial_assertion(input.span()));
+                    }
+                }
+
                 let t1 = self.visit_expression(left, &None);
                 let t2 = self.visit_expression(right, &None);
 
                 // Check that the types are equal.
-                self.check_eq_types(&t1, &t2, input.span());
+                self.check_eq_types(&t1, &t2, (left.span(), right.span()), input.span());
             }
         }
     }
@@ -102,6 +116,11 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
     fn visit_conditional(&mut self, input: &'a ConditionalStatement) {
         self.visit_expression(&input.condition, &Some(Type::Boolean));
 
+        // Warn about conditions that are boolean literals.
+        if let Expression::Literal(Literal::Boolean(value, ..)) = &input.condition {
+            self.emit_warning(TypeCheckerWarning::constant_condition(value, input.condition.span()));
+        }
+
         let mut then_block_has_return = false;
         let mut otherwise_block_has_return = false;
 
@@ -110,6 +129,8 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
 
         // Set the `has_return` flag for the then-block.
         let previous_has_return = core::mem::replace(&mut self.has_return, then_block_has_return);
+        // Set the `missing_return` span for the then-block.
+        let previous_missing_return = self.missing_return.take();
         // Set the `has_finalize` flag for the then-block.
         let previous_has_finalize = core::mem::replace(&mut self.has_finalize, then_block_has_finalize);
 
@@ -117,9 +138,13 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
 
         // Store the `has_return` flag for the then-block.
         then_block_has_return = self.has_return;
+        // Store the `missing_return` span for the then-block.
+        let then_block_missing_return = self.missing_return.take();
         // Store the `has_finalize` flag for the then-block.
         then_block_has_finalize = self.has_finalize;
 
+        let mut otherwise_block_missing_return = None;
+
         if let Some(otherwise) = &input.otherwise {
             // Set the `has_return` flag for the otherwise-block.
             self.has_return = otherwise_block_has_return;
@@ -137,12 +162,23 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
 
             // Store the `has_return` flag for the otherwise-block.
             otherwise_block_has_return = self.has_return;
+            // Store the `missing_return` span for the otherwise-block.
+            otherwise_block_missing_return = self.missing_return.take();
             // Store the `has_finalize` flag for the otherwise-block.
             otherwise_block_has_finalize = self.has_finalize;
         }
 
         // Restore the previous `has_return` flag.
         self.has_return = previous_has_return || (then_block_has_return && otherwise_block_has_return);
+        // If exactly one of the branches returns, record the innermost branch that does not, so that a missing return can be reported precisely.
+        self.missing_return = match (then_block_has_return, otherwise_block_has_return) {
+            (true, false) => Some(otherwise_block_missing_return.unwrap_or_else(|| match &input.otherwise {
+                Some(otherwise) => otherwise.span(),
+                None => input.span,
+            })),
+            (false, true) => Some(then_block_missing_return.unwrap_or(input.then.span)),
+            _ => previous_missing_return,
+        };
         // Restore the previous `has_finalize` flag.
         self.has_finalize = previous_has_finalize || (then_block_has_finalize && otherwise_block_has_finalize);
     }
@@ -276,6 +312,17 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
             }
             _ => self.emit_err(TypeCheckerError::lhs_must_be_identifier_or_tuple(input.place.span())),
         }
+
+        // Track the defined variables, so that a warning is emitted if they are never used.
+        match &input.place {
+            Expression::Identifier(identifier) => self.track_variable(identifier.name, identifier.span),
+            Expression::Tuple(tuple_expression) => tuple_expression.elements.iter().for_each(|expression| {
+                if let Expression::Identifier(identifier) = expression {
+                    self.track_variable(identifier.name, identifier.span)
+                }
+            }),
+            _ => {}
+        }
     }
 
     fn visit_expression_statement(&mut self, input: &'a ExpressionStatement) {
@@ -16,14 +16,28 @@
 
 use crate::{CallGraph, StructGraph, SymbolTable, TypeTable};
 
-use leo_ast::{CoreConstant, CoreFunction, Identifier, IntegerType, MappingType, Node, Type, Variant};
-use leo_errors::{emitter::Handler, TypeCheckerError};
+use leo_ast::{
+    BinaryExpression,
+    BinaryOperation,
+    CoreConstant,
+    CoreFunction,
+    Expression,
+    Identifier,
+    IntegerType,
+    Literal,
+    MappingType,
+    Node,
+    Type,
+    Variant,
+};
+use leo_errors::{emitter::Handler, TypeCheckerError, TypeCheckerWarning};
 use leo_span::{Span, Symbol};
 
 use snarkvm_console::network::{Network, Testnet3};
 
+use indexmap::{IndexMap, IndexSet};
 use itertools::Itertools;
-use std::cell::RefCell;
+use std::{cell::RefCell, cmp::Ordering, str::FromStr};
 
 pub struct TypeChecker<'a> {
     /// The symbol table for the program.
@@ -42,6 +56,8 @@ pub struct TypeChecker<'a> {
     pub(crate) variant: Option<Variant>,
     /// Whether or not the function that we are currently traversing has a return statement.
     pub(crate) has_return: bool,
+    /// The innermost branch of the function that we are currently traversing that does not return, if any.
+    pub(crate) missing_return: Option<Span>,
     /// Whether or not the function that we are currently traversing invokes the finalize block.
     pub(crate) has_finalize: bool,
 
@@ -51,6 +67,13 @@ pub struct TypeChecker<'a> {
     pub(crate) is_imported: bool,
     /// Whether or not we are currently traversing a return statement.
     pub(crate) is_return: bool,
+
+    /// For each scope that we are currently in, the variables defined in it that have not been used yet.
+    pub(crate) unused_variables: Vec<IndexMap<Symbol, Span>>,
+    /// A mapping from the structs and functions defined in imported programs to the name of the program.
+    pub(crate) imported_symbols: IndexMap<Symbol, Symbol>,
+    /// The imported programs that have been referenced.
+    pub(crate) used_imports: RefCell<IndexSet<Symbol>>,
 }
 
 const ADDRESS_TYPE: Type = Type::Address;
@@ -114,10 +137,14 @@ impl<'a> TypeChecker<'a> {
             function: None,
             variant: None,
             has_return: false,
+            missing_return: None,
             has_finalize: false,
             is_finalize: false,
             is_imported: false,
             is_return: false,
+            unused_variables: Vec::new(),
+            imported_symbols: IndexMap::new(),
+            used_imports: RefCell::new(IndexSet::new()),
         }
     }
 
@@ -126,6 +153,7 @@ impl<'a> TypeChecker<'a> {
         let previous_symbol_table = std::mem::take(&mut self.symbol_table);
         self.symbol_table.swap(previous_symbol_table.borrow().lookup_scope_by_index(index).unwrap());
         self.symbol_table.borrow_mut().parent = Some(Box::new(previous_symbol_table.into_inner()));
+        self.unused_variables.push(IndexMap::new());
     }
 
     /// Creates a new child scope.
@@ -143,6 +171,46 @@ impl<'a> TypeChecker<'a> {
         let previous_symbol_table = *self.symbol_table.borrow_mut().parent.take().unwrap();
         self.symbol_table.swap(previous_symbol_table.lookup_scope_by_index(index).unwrap());
         self.symbol_table = RefCell::new(previous_symbol_table);
+
+        // Warn about the variables in the scope that were never used.
+        if let Some(unused_variables) = self.unused_variables.pop() {
+            unused_variables
+                .into_iter()
+                .for_each(|(name, span)| self.emit_warning(TypeCheckerWarning::unused_variable(name, span)));
+        }
+    }
+
+    /// Records a variable defined by a `let` statement, so that a warning is emitted if it is never used.
+    pub(crate) fn track_variable(&mut self, name: Symbol, span: Span) {
+        // Imported programs are not linted.
+        if !self.is_imported {
+            if let Some(scope) = self.unused_variables.last_mut() {
+                scope.insert(name, span);
+            }
+        }
+    }
+
+    /// Marks a variable as used.
+    pub(crate) fn use_variable(&mut self, name: Symbol) {
+        // Note that shadowing is not allowed, so the innermost definition is the one being used.
+        if let Some(scope) = self.unused_variables.iter_mut().rev().find(|scope| scope.contains_key(&name)) {
+            scope.shift_remove(&name);
+        }
+    }
+
+    /// Marks the imported program that defines `name` as used, if one exists.
+    pub(crate) fn use_imported_symbol(&self, name: Symbol) {
+        if let Some(program) = self.imported_symbols.get(&name) {
+            self.use_import(*program);
+        }
+    }
+
+    /// Marks an imported program as used.
+    pub(crate) fn use_import(&self, program: Symbol) {
+        // Note that references made by the imported programs themselves do not count.
+        if !self.is_imported {
+            self.used_imports.borrow_mut().insert(program);
+        }
     }
 
     /// Emits a type checker error.
@@ -150,6 +218,108 @@ impl<'a> TypeChecker<'a> {
         self.handler.emit_err(err);
     }
 
+    /// Emits a type checker warning.
+    /// Warnings are not emitted for imported programs, since their authors are not the ones compiling them.
+    pub(crate) fn emit_warning(&self, warning: TypeCheckerWarning) {
+        if !self.is_imported {
+            self.handler.emit_warning(warning.into());
+        }
+    }
+
+    /// Returns the value of the comparison if it is the same for every input, e.g. `x == x` or `x <= 255u8` for a `u8`.
+    pub(crate) fn constant_comparison(input: &BinaryExpression) -> Option<bool> {
+        // Returns whether `value` is the minimum and the maximum of its type, respectively.
+        fn is_bound<I: FromStr + PartialEq>(value: &str, min: I, max: I) -> (bool, bool) {
+            match value.replace('_', "").parse::<I>() {
+                Ok(value) => (value == min, value == max),
+                Err(_) => (false, false),
+            }
+        }
+
+        match (&*input.left, &*input.right) {
+            // Comparing a variable with itself.
+            (Expression::Identifier(left), Expression::Identifier(right)) if left.name == right.name => {
+                match input.op {
+                    BinaryOperation::Eq | BinaryOperation::Gte | BinaryOperation::Lte => Some(true),
+                    BinaryOperation::Neq | BinaryOperation::Gt | BinaryOperation::Lt => Some(false),
+                    _ => None,
+                }
+            }
+            // Comparing two literals, e.g. `1u8 > 2u8`.
+            (Expression::Literal(left), Expression::Literal(right)) => Self::compare_literals(input.op, left, right),
+            // Comparing a non-constant expression with the minimum or maximum of its integer type.
+            (left, right) => {
+                // Normalize the comparison so that the literal is on the right hand side.
+                let (op, value) = match (left, right) {
+                    (_, Expression::Literal(Literal::Integer(type_, value, ..))) => (input.op, (type_, value)),
+                    (Expression::Literal(Literal::Integer(type_, value, ..)), _) => match input.op {
+                        BinaryOperation::Gt => (BinaryOperation::Lt, (type_, value)),
+                        BinaryOperation::Gte => (BinaryOperation::Lte, (type_, value)),
+                        BinaryOperation::Lt => (BinaryOperation::Gt, (type_, value)),
+                        BinaryOperation::Lte => (BinaryOperation::Gte, (type_, value)),
+                        op => (op, (type_, value)),
+                    },
+                    _ => return None,
+                };
+                let (is_min, is_max) = match value {
+                    (IntegerType::U8, value) => is_bound(value, u8::MIN, u8::MAX),
+                    (IntegerType::U16, value) => is_bound(value, u16::MIN, u16::MAX),
+                    (IntegerType::U32, value) => is_bound(value, u32::MIN, u32::MAX),
+                    (IntegerType::U64, value) => is_bound(value, u64::MIN, u64::MAX),
+                    (IntegerType::U128, value) => is_bound(value, u128::MIN, u128::MAX),
+                    (IntegerType::I8, value) => is_bound(value, i8::MIN, i8::MAX),
+                    (IntegerType::I16, value) => is_bound(value, i16::MIN, i16::MAX),
+                    (IntegerType::I32, value) => is_bound(value, i32::MIN, i32::MAX),
+                    (IntegerType::I64, value) => is_bound(value, i64::MIN, i64::MAX),
+                    (IntegerType::I128, value) => is_bound(value, i128::MIN, i128::MAX),
+                };
+                match op {
+                    BinaryOperation::Lt if is_min => Some(false),
+                    BinaryOperation::Gte if is_min => Some(true),
+                    BinaryOperation::Gt if is_max => Some(false),
+                    BinaryOperation::Lte if is_max => Some(true),
+                    _ => None,
+                }
+            }
+        }
+    }
+
+    /// Returns the value of a comparison between two literals, if they are booleans or integers of the same type.
+    fn compare_literals(op: BinaryOperation, left: &Literal, right: &Literal) -> Option<bool> {
+        // Returns the ordering of two integers of type `I`, if both are well-formed.
+        fn compare<I: FromStr + Ord>(left: &str, right: &str) -> Option<Ordering> {
+            Some(left.replace('_', "").parse::<I>().ok()?.cmp(&right.replace('_', "").parse::<I>().ok()?))
+        }
+
+        let ordering = match (left, right) {
+            // Booleans can only be compared for equality.
+            (Literal::Boolean(left, ..), Literal::Boolean(right, ..))
+                if matches!(op, BinaryOperation::Eq | BinaryOperation::Neq) =>
+            {
+                left.cmp(right)
+            }
+            (Literal::Integer(left_type, left, ..), Literal::Integer(right_type, right, ..))
+                if left_type == right_type =>
+            {
+                match left_type.is_signed() {
+                    true => compare::<i128>(left, right)?,
+                    false => compare::<u128>(left, right)?,
+                }
+            }
+            _ => return None,
+        };
+
+        match op {
+            BinaryOperation::Eq => Some(ordering == Ordering::Equal),
+            BinaryOperation::Neq => Some(ordering != Ordering::Equal),
+            BinaryOperation::Lt => Some(ordering == Ordering::Less),
+            BinaryOperation::Lte => Some(ordering != Ordering::Greater),
+            BinaryOperation::Gt => Some(ordering == Ordering::Greater),
+            BinaryOperation::Gte => Some(ordering != Ordering::Less),
+            _ => None,
+        }
+    }
+
     /// Emits an error to the handler if the given type is invalid.
     fn check_type(&self, is_valid: impl Fn(&Type) -> bool, error_string: String, type_: &Option<Type>, span: Span) {
         if let Some(type_) = type_ {
@@ -159,9 +329,12 @@ impl<'a> TypeChecker<'a> {
         }
     }
 
-    /// Emits an error if the two given types are not equal.
-    pub(crate) fn check_eq_types(&self, t1: &Option<Type>, t2: &Option<Type>, span: Span) {
+    /// Emits an error if the types of two operands are not equal.
+    /// Integer types of different widths are reported at both `operands`, and other mismatches at `span`.
+    pub(crate) fn check_eq_types(&self, t1: &Option<Type>, t2: &Option<Type>, operands: (Span, Span), span: Span) {
         match (t1, t2) {
+            (Some(Type::Integer(t1)), Some(Type::Integer(t2))) if t1 != t2 => self
+                .emit_err(TypeCheckerError::mismatched_integer_types(t1, t2, operands.0).with_related_span(operands.1)),
             (Some(t1), Some(t2)) if !Type::eq_flat(t1, t2) => {
                 self.emit_err(TypeCheckerError::type_should_be(t1, t2, span))
             }
@@ -1112,6 +1285,8 @@ impl<'a> TypeChecker<'a> {
                 }
                 is_valid &= self.assert_type_is_valid(array_type.element_type(), span)
             }
+            // Note that naming a struct defined in an imported program is a use of that program.
+            Type::Identifier(identifier) => self.use_imported_symbol(identifier.name),
             _ => {} // Do nothing.
         }
         is_valid
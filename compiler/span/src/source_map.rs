@@ -299,6 +299,12 @@ impl SpanLocation {
     }
 }
 
+impl fmt::Display for SpanLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}:{}", self.source_file.name, self.line_start, self.col_start)
+    }
+}
+
 /// File / Line / Column information on a `BytePos`.
 pub struct LineCol {
     /// Information on the original source.
@@ -245,6 +245,7 @@ symbols! {
     If: "if",
     In: "in",
     import,
+    include,
     increment,
     inline,
     input,
@@ -258,6 +259,9 @@ symbols! {
     SelfUpper: "Self",
     signer,
     Star: "*",
+    test,
+    should_fail,
+    max_constraints,
     then,
     transition,
     Type: "type",
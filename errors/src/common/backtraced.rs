@@ -84,22 +84,18 @@ impl Backtraced {
 
     /// Gets a unique error identifier.
     pub fn error_code(&self) -> String {
-        format!(
-            "E{error_type}{code_identifier:0>3}{exit_code:0>4}",
-            error_type = self.type_,
-            code_identifier = self.code_identifier,
-            exit_code = self.code,
-        )
+        Self::message_code(true, &self.type_, self.code_identifier, self.code)
     }
 
     /// Gets a unique warning identifier.
     pub fn warning_code(&self) -> String {
-        format!(
-            "W{error_type}{code_identifier:0>3}{exit_code:0>4}",
-            error_type = self.type_,
-            code_identifier = self.code_identifier,
-            exit_code = self.code,
-        )
+        Self::message_code(false, &self.type_, self.code_identifier, self.code)
+    }
+
+    /// Returns the unique identifier of a message from its parts, e.g. `EPAR0370005`.
+    pub fn message_code(error: bool, type_: &str, code_identifier: i8, code: i32) -> String {
+        let kind = if error { 'E' } else { 'W' };
+        format!("{kind}{type_}{code_identifier:0>3}{code:0>4}")
     }
 }
 
@@ -0,0 +1,137 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Backtraced, Formatted};
+
+use leo_span::{symbol::with_session_globals, Span};
+
+use serde::{Deserialize, Serialize};
+
+/// A machine-readable error or warning, e.g. for editors and CI to annotate the source with.
+///
+/// The schema is stable: fields are only ever added, and a field is `null` or empty when it is unknown.
+/// ```json
+/// {
+///   "code": "ETYC0372003",
+///   "severity": "error",
+///   "message": "Expected type `u8` but type `u16` was found",
+///   "file": "src/main.leo",
+///   "span": { "file": "src/main.leo", "line_start": 3, "column_start": 16, "line_end": 3, "column_end": 21 },
+///   "related_spans": [],
+///   "suggestion": null
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Diagnostic {
+    /// The code of the message, e.g. `ETYC0372003`, if it has one.
+    pub code: Option<String>,
+    /// Whether the message is an error or a warning.
+    pub severity: Severity,
+    /// The message itself, without its code or location.
+    pub message: String,
+    /// The file that the message is about, if any.
+    pub file: Option<String>,
+    /// The location of the message in `file`, if any.
+    pub span: Option<DiagnosticSpan>,
+    /// Other locations that the message refers to, e.g. an earlier definition of the same name.
+    pub related_spans: Vec<DiagnosticSpan>,
+    /// How to fix the problem, if there is a known fix.
+    pub suggestion: Option<String>,
+}
+
+/// The severity of a [`Diagnostic`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// The message is an error, so compilation failed.
+    Error,
+    /// The message is a warning.
+    Warning,
+}
+
+/// A location in a source file.
+/// Lines and columns start at 1, and the end of the span is exclusive.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiagnosticSpan {
+    /// The file that the span is in.
+    pub file: String,
+    /// The line that the span starts on.
+    pub line_start: usize,
+    /// The column that the span starts at.
+    pub column_start: usize,
+    /// The line that the span ends on.
+    pub line_end: usize,
+    /// The column that the span ends at.
+    pub column_end: usize,
+}
+
+impl DiagnosticSpan {
+    /// Returns the location of `span` in the source map, or `None` if it is not in any source file.
+    pub fn from_span(span: Span) -> Option<Self> {
+        with_session_globals(|s| s.source_map.span_to_location(span)).map(|location| Self {
+            file: location.source_file.name.to_string(),
+            line_start: location.line_start,
+            column_start: location.col_start,
+            line_end: location.line_stop,
+            column_end: location.col_stop,
+        })
+    }
+}
+
+impl Diagnostic {
+    /// Returns a diagnostic of a message that has no code or location.
+    pub fn from_message(message: impl ToString) -> Self {
+        Self {
+            code: None,
+            severity: Severity::Error,
+            message: message.to_string(),
+            file: None,
+            span: None,
+            related_spans: Vec::new(),
+            suggestion: None,
+        }
+    }
+
+    /// Returns the diagnostic of a message at the given span, if any, which also refers to `related_spans`.
+    fn new(backtraced: &Backtraced, span: Option<Span>, related_spans: &[Span]) -> Self {
+        let (severity, code) = match backtraced.error {
+            true => (Severity::Error, backtraced.error_code()),
+            false => (Severity::Warning, backtraced.warning_code()),
+        };
+        let span = span.and_then(DiagnosticSpan::from_span);
+        Self {
+            code: Some(code),
+            severity,
+            message: backtraced.message.clone(),
+            file: span.as_ref().map(|span| span.file.clone()),
+            span,
+            related_spans: related_spans.iter().copied().filter_map(DiagnosticSpan::from_span).collect(),
+            suggestion: backtraced.help.clone(),
+        }
+    }
+}
+
+impl From<&Backtraced> for Diagnostic {
+    fn from(backtraced: &Backtraced) -> Self {
+        Self::new(backtraced, None, &[])
+    }
+}
+
+impl From<&Formatted> for Diagnostic {
+    fn from(formatted: &Formatted) -> Self {
+        Self::new(&formatted.backtrace, Some(formatted.span), &formatted.related_spans)
+    }
+}
@@ -36,6 +36,8 @@ use std::fmt;
 pub struct Formatted {
     /// The formatted error span information.
     pub span: Span,
+    /// Other locations that the error refers to, e.g. an earlier definition of the same name.
+    pub related_spans: Vec<Span>,
     /// The backtrace to track where the Leo error originated.
     pub backtrace: Backtraced,
 }
@@ -58,6 +60,7 @@ impl Formatted {
     {
         Self {
             span,
+            related_spans: Vec::new(),
             backtrace: Backtraced::new_from_backtrace(
                 message.to_string(),
                 help,
@@ -86,36 +89,65 @@ impl Formatted {
     }
 }
 
-impl fmt::Display for Formatted {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let underline = |mut start: usize, mut end: usize| -> String {
-            if start > end {
-                std::mem::swap(&mut start, &mut end)
-            }
-
-            let mut underline = String::new();
+/// Writes the location of `span` and the source lines that it covers, with the span underlined:
+///     --> file.leo: 2:8
+///      |
+///    2 | let a = x;
+///      |         ^
+fn write_span(f: &mut fmt::Formatter, span: Span) -> fmt::Result {
+    let underline = |mut start: usize, mut end: usize| -> String {
+        if start > end {
+            std::mem::swap(&mut start, &mut end)
+        }
 
-            for _ in 0..start {
-                underline.push(' ');
-                end -= 1;
-            }
+        let mut underline = String::new();
 
-            for _ in 0..end {
-                underline.push('^');
-            }
+        for _ in 0..start {
+            underline.push(' ');
+            end -= 1;
+        }
 
-            underline
-        };
+        for _ in 0..end {
+            underline.push('^');
+        }
 
-        let (loc, contents) = with_session_globals(|s| {
-            (
-                s.source_map.span_to_location(self.span).unwrap_or_else(SpanLocation::dummy),
-                s.source_map.line_contents_of_span(self.span).unwrap_or_else(|| "<contents unavailable>".to_owned()),
-            )
-        });
+        underline
+    };
+
+    let (loc, contents) = with_session_globals(|s| {
+        (
+            s.source_map.span_to_location(span).unwrap_or_else(SpanLocation::dummy),
+            s.source_map.line_contents_of_span(span).unwrap_or_else(|| "<contents unavailable>".to_owned()),
+        )
+    });
+
+    let underlined = underline(loc.col_start, loc.col_stop);
+
+    write!(
+        f,
+        "{indent     }--> {path}:{line_start}:{start}\n\
+        {indent     } |\n",
+        indent = INDENT,
+        path = &loc.source_file.name,
+        line_start = loc.line_start,
+        start = loc.col_start,
+    )?;
+
+    for (line_no, line) in contents.lines().enumerate() {
+        writeln!(
+            f,
+            "{line_no:width$} | {text}",
+            width = INDENT.len(),
+            line_no = loc.line_start + line_no,
+            text = line,
+        )?;
+    }
 
-        let underlined = underline(loc.col_start, loc.col_stop);
+    write!(f, "{INDENT     } |{underlined}",)
+}
 
+impl fmt::Display for Formatted {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let (kind, code) =
             if self.backtrace.error { ("Error", self.error_code()) } else { ("Warning", self.warning_code()) };
 
@@ -132,28 +164,14 @@ impl fmt::Display for Formatted {
             write!(f, "{message}")?;
         };
 
-        write!(
-            f,
-            "\n{indent     }--> {path}:{line_start}:{start}\n\
-            {indent     } |\n",
-            indent = INDENT,
-            path = &loc.source_file.name,
-            line_start = loc.line_start,
-            start = loc.col_start,
-        )?;
+        writeln!(f)?;
+        write_span(f, self.span)?;
 
-        for (line_no, line) in contents.lines().enumerate() {
-            writeln!(
-                f,
-                "{line_no:width$} | {text}",
-                width = INDENT.len(),
-                line_no = loc.line_start + line_no,
-                text = line,
-            )?;
+        for span in self.related_spans.iter() {
+            writeln!(f, "\n{INDENT     } |")?;
+            write_span(f, *span)?;
         }
 
-        write!(f, "{INDENT     } |{underlined}",)?;
-
         if let Some(help) = &self.backtrace.help {
             write!(
                 f,
@@ -28,7 +28,7 @@ macro_rules! create_messages {
     };
     ($(#[$error_type_docs:meta])* $type_:ident, code_mask: $code_mask:expr, code_prefix: $code_prefix:expr, $($(#[$docs:meta])* @$formatted_or_backtraced_list:ident $names:ident { args: ($($arg_names:ident: $arg_types:ty$(,)?)*), msg: $messages:expr, help: $helps:expr, })*) => {
         #[allow(unused_imports)] // Allow unused for errors that only use formatted or backtraced errors.
-        use $crate::{Backtraced, Formatted, LeoMessageCode};
+        use $crate::{Backtraced, Diagnostic, Formatted, LeoMessageCode};
 
         use backtrace::Backtrace;
 
@@ -69,6 +69,14 @@ macro_rules! create_messages {
                 }
             }
 
+            #[inline(always)]
+            fn diagnostic(&self) -> Diagnostic {
+                match self {
+                    Self::Formatted(formatted) => formatted.into(),
+                    Self::Backtraced(backtraced) => backtraced.into()
+                }
+            }
+
             #[inline(always)]
             fn code_mask() -> i32 {
                 $code_mask
@@ -90,6 +98,33 @@ macro_rules! create_messages {
         impl $type_ {
             create_messages!(@step 0i32, $(($(#[$docs])* $formatted_or_backtraced_list, $names($($arg_names: $arg_types,)*), $messages, $helps),)*);
         }
+
+        impl $type_ {
+            /// Adds another location that the message refers to, e.g. an earlier definition of the same name.
+            /// Messages without a location are returned unchanged.
+            pub fn with_related_span(self, span: leo_span::Span) -> Self {
+                match self {
+                    Self::Formatted(mut formatted) => {
+                        formatted.related_spans.push(span);
+                        Self::Formatted(formatted)
+                    }
+                    backtraced => backtraced,
+                }
+            }
+
+            /// Returns the code and the name of each message, in the order that their codes are assigned.
+            pub fn message_codes() -> Vec<(String, &'static str)> {
+                let names: &[&'static str] = &[$(stringify!($names)),*];
+                let (error, type_, identifier) = (Self::is_error(), Self::message_type(), Self::code_identifier());
+                names
+                    .iter()
+                    .enumerate()
+                    .map(|(index, name)| {
+                        (Backtraced::message_code(error, &type_, identifier, Self::code_mask() + index as i32), *name)
+                    })
+                    .collect()
+            }
+        }
     };
     // Matches the function if it is a formatted message.
     (@step $code:expr, ($(#[$error_func_docs:meta])* formatted, $name:ident($($arg_names:ident: $arg_types:ty,)*), $message:expr, $help:expr), $(($(#[$docs:meta])* $formatted_or_backtraced_tail:ident, $names:ident($($tail_arg_names:ident: $tail_arg_types:ty,)*), $messages:expr, $helps:expr),)*) => {
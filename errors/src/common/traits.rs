@@ -14,6 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::Diagnostic;
+
 /// MessageCode trait that all Errors should implement.
 pub trait LeoMessageCode: Sized {
     /// Returns the error's exit code for the program.
@@ -25,6 +27,9 @@ pub trait LeoMessageCode: Sized {
     /// Returns the prefixed warning identifier.
     fn warning_code(&self) -> String;
 
+    /// Returns the machine-readable form of the message.
+    fn diagnostic(&self) -> Diagnostic;
+
     /// Returns the messages's exit code mask, as to avoid conflicts.
     fn code_mask() -> i32;
 
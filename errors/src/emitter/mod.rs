@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::LeoWarning;
+use crate::{Diagnostic, LeoWarning};
 
 use super::LeoError;
 use core::{default::Default, fmt};
@@ -53,6 +53,38 @@ impl Emitter for StderrEmitter {
     }
 }
 
+/// An `Emitter` that writes each message to the standard error as a JSON [`Diagnostic`] on its own line.
+#[derive(Default)]
+pub struct JsonEmitter {
+    /// Exit code of the last emitted error.
+    last_error_code: Option<i32>,
+}
+
+impl JsonEmitter {
+    /// Writes the diagnostic as a line of JSON.
+    pub fn emit_diagnostic(diagnostic: &Diagnostic) {
+        // Note that serializing a `Diagnostic` cannot fail, as all of its keys are strings.
+        eprintln!("{}", serde_json::to_string(diagnostic).unwrap());
+    }
+}
+
+impl Emitter for JsonEmitter {
+    fn emit_err(&mut self, err: LeoError) {
+        self.last_error_code = Some(err.exit_code());
+        if let Some(diagnostic) = err.diagnostic() {
+            Self::emit_diagnostic(&diagnostic);
+        }
+    }
+
+    fn last_emitted_err_code(&self) -> Option<i32> {
+        self.last_error_code
+    }
+
+    fn emit_warning(&mut self, warning: LeoWarning) {
+        Self::emit_diagnostic(&warning.diagnostic());
+    }
+}
+
 /// A buffer of `T`s.
 #[derive(Debug)]
 pub struct Buffer<T>(Vec<T>);
@@ -258,8 +290,8 @@ impl Handler {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::ParserError;
-    use leo_span::{symbol::create_session_if_not_set_then, Span};
+    use crate::{DiagnosticSpan, ParserError, Severity};
+    use leo_span::{source_map::FileName, span::BytePos, symbol::create_session_if_not_set_then, Span};
 
     #[test]
     fn fresh_no_errors() {
@@ -296,4 +328,30 @@ mod tests {
             Handler::with(|_| Ok(())).unwrap();
         })
     }
+
+    #[test]
+    fn diagnostic_has_location() {
+        create_session_if_not_set_then(|s| {
+            let source = "program test.aleo {\n    transition\n}\n";
+            let file = s.source_map.new_source(source, FileName::Custom("main.leo".to_string()));
+            let span = Span::new(file.start_pos + BytePos(24), file.start_pos + BytePos(34));
+
+            let diagnostic = LeoError::from(ParserError::unexpected_eof(span)).diagnostic().unwrap();
+            assert_eq!(diagnostic.code.as_deref(), Some("EPAR0370003"));
+            assert_eq!(diagnostic.severity, Severity::Error);
+            assert_eq!(diagnostic.file.as_deref(), Some("main.leo"));
+            assert_eq!(
+                diagnostic.span,
+                Some(DiagnosticSpan {
+                    file: "main.leo".to_string(),
+                    line_start: 2,
+                    column_start: 5,
+                    line_end: 2,
+                    column_end: 15,
+                })
+            );
+
+            assert_eq!(LeoError::LastErrorCode(1).diagnostic(), None);
+        })
+    }
 }
@@ -178,4 +178,81 @@ create_messages!(
         msg: format!("Failed to write file.\nIO Error: {error}"),
         help: None,
     }
+
+    @backtraced
+    warnings_denied {
+        args: (count: impl Display),
+        msg: format!("The build emitted {count} warning(s), and warnings are denied."),
+        help: Some("Fix the warnings, or build without `--deny-warnings`.".to_string()),
+    }
+
+    @backtraced
+    tests_failed {
+        args: (failed: impl Display, total: impl Display),
+        msg: format!("{failed} of {total} test(s) failed."),
+        help: None,
+    }
+
+    @backtraced
+    files_not_formatted {
+        args: (count: impl Display),
+        msg: format!("{count} file(s) are not formatted."),
+        help: Some("Run `leo fmt` to format them.".to_string()),
+    }
+
+    @backtraced
+    failed_to_count_constraints {
+        args: (error: impl Display),
+        msg: format!("Failed to count the constraints of the expression.\nSnarkVM Error: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    failed_to_execute_bench {
+        args: (error: impl Display),
+        msg: format!("Failed to execute the `bench` command.\nSnarkVM Error: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    constraint_budget_exceeded {
+        args: (report: impl Display),
+        msg: format!("The constraint budget was exceeded.\n{report}"),
+        help: Some("Reduce the constraints of the listed transitions, or raise the budget with `@max_constraints` or with `max_constraints` in `program.json`.".to_string()),
+    }
+
+    @backtraced
+    failed_to_prove {
+        args: (error: impl Display),
+        msg: format!("Failed to prove the execution.\nSnarkVM Error: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    failed_to_verify {
+        args: (error: impl Display),
+        msg: format!("Failed to verify the proof.\nSnarkVM Error: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    unknown_message_code {
+        args: (code: impl Display),
+        msg: format!("There is no error or warning with the code `{code}`."),
+        help: Some("Codes are printed with each error and warning, e.g. `ETYC0372003`.".to_string()),
+    }
+
+    @backtraced
+    invalid_lint_config {
+        args: (error: impl Display),
+        msg: format!("Failed to read the lint configuration in `leo.toml`.\n{error}"),
+        help: Some("Set each lint to `allow`, `warn`, or `deny` in the `[lint]` table, e.g. `field_comparison = \"deny\"`.".to_string()),
+    }
+
+    @backtraced
+    lints_denied {
+        args: (count: impl Display),
+        msg: format!("The linter found {count} denied lint(s)."),
+        help: Some("Fix the code, or lower the level of the lints in `leo.toml`.".to_string()),
+    }
 );
@@ -70,4 +70,11 @@ create_messages!(
         msg: format!("The program scope name `{program_scope_name}` must match `{file_name}`."),
         help: None,
     }
+
+    @formatted
+    cyclic_import {
+        args: (chain: impl Display),
+        msg: format!("Cyclic import detected: {chain}"),
+        help: Some("Remove one of the imports in the cycle.".to_string()),
+    }
 );
@@ -55,4 +55,124 @@ create_messages!(
         ),
         help: None,
     }
+
+    /// For when the input file provides a value for an input that a function does not have.
+    @formatted
+    unexpected_input {
+        args: (name: impl Display, function: impl Display),
+        msg: format!("function '{function}' does not have an input named '{name}'"),
+        help: Some(format!("Remove this definition from the `[{function}]` section of the input file.")),
+    }
+
+    /// For when the input file does not provide a value for a function input.
+    @formatted
+    missing_input {
+        args: (name: impl Display, function: impl Display),
+        msg: format!("the input file does not provide a value for input '{name}' of function '{function}'"),
+        help: Some(format!("Add a definition for `{name}` to the `[{function}]` section of the input file.")),
+    }
+
+    /// For when the type of a value in the input file does not match the type of the function input.
+    @formatted
+    input_type_mismatch {
+        args: (name: impl Display, expected: impl Display, received: impl Display, declared_at: impl Display),
+        msg: format!("input '{name}' has type '{expected}', but the input file provides a value of type '{received}'"),
+        help: Some(format!("'{name}' is declared with type '{expected}' at {declared_at}.")),
+    }
+
+    /// For when an input refers to a struct or record that is not declared.
+    @formatted
+    undefined_struct {
+        args: (name: impl Display),
+        msg: format!("a struct or record declaration does not exist for '{name}'"),
+        help: None,
+    }
+
+    /// For when an array value in the input file has the wrong number of elements.
+    @formatted
+    array_length_mismatch {
+        args: (expected: impl Display, received: impl Display),
+        msg: format!("expected an array of {expected} elements, but the input file provides {received}"),
+        help: None,
+    }
+
+    /// For when a struct value in the input file initializes a member that is not declared.
+    @formatted
+    unknown_struct_member {
+        args: (struct_: impl Display, member: impl Display),
+        msg: format!("struct '{struct_}' does not have a member named '{member}'"),
+        help: None,
+    }
+
+    /// For when a struct value in the input file does not initialize a declared member.
+    @formatted
+    missing_struct_member {
+        args: (struct_: impl Display, member: impl Display),
+        msg: format!("the value of struct '{struct_}' is missing member '{member}'"),
+        help: None,
+    }
+
+    /// For when a selected section does not exist in the input file.
+    @backtraced
+    missing_section {
+        args: (section: impl Display),
+        msg: format!("the input file does not have a section named `[{section}]`"),
+        help: None,
+    }
+
+    /// For when a JSON input file cannot be converted into program inputs.
+    @backtraced
+    invalid_json_input {
+        args: (reason: impl Display),
+        msg: format!("invalid JSON input: {reason}"),
+        help: None,
+    }
+
+    /// For when the inputs given to an `InputBuilder` do not match a function.
+    @backtraced
+    invalid_builder_input {
+        args: (reason: impl Display),
+        msg: format!("invalid input: {reason}"),
+        help: None,
+    }
+
+    /// For when an included input file does not exist.
+    @formatted
+    include_not_found {
+        args: (path: impl Display),
+        msg: format!("the included input file `{path}` does not exist"),
+        help: Some("Included paths are relative to the directory of the including file.".to_string()),
+    }
+
+    /// For when an input file includes itself, directly or through other includes.
+    @formatted
+    include_cycle {
+        args: (path: impl Display),
+        msg: format!("the input file `{path}` includes itself"),
+        help: None,
+    }
+
+    /// For when the input file provides more than one value for a function input.
+    @formatted
+    duplicate_input {
+        args: (name: impl Display, function: impl Display),
+        msg: format!("the input file provides more than one value for input '{name}' of function '{function}'"),
+        help: Some(format!("Keep one definition of `{name}` in the `[{function}]` section of the input file.")),
+    }
+
+    /// For when sections of the input file give different values to a constant input.
+    @formatted
+    conflicting_constant_input {
+        args: (name: impl Display, function: impl Display),
+        msg: format!("the input file gives different values to constant input '{name}' of function '{function}'"),
+        help: Some("Constant inputs are compiled into the program, so sections must agree on them.".to_string()),
+    }
+
+    /// For when the input file does not provide a value for a constant input.
+    @formatted
+    missing_constant_input {
+        args: (name: impl Display, function: impl Display),
+        msg: format!("the input file does not provide a value for constant input '{name}' of function '{function}'"),
+        help: Some(format!("Add a `constant {name}` definition to the `[{function}]` section of the input file.")),
+    }
 );
@@ -0,0 +1,102 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::create_messages;
+use std::fmt::{Debug, Display};
+
+create_messages!(
+    /// InterpreterError enum that represents all the errors for the interpreter in the `leo-passes` crate.
+    InterpreterError,
+    code_mask: 8000i32,
+    code_prefix: "INT",
+
+    @formatted
+    assertion_failed {
+        args: (),
+        msg: "The assertion failed.",
+        help: None,
+    }
+
+    @formatted
+    assert_eq_failed {
+        args: (left: impl Display, right: impl Display),
+        msg: format!("The assertion failed: `{left}` is not equal to `{right}`."),
+        help: None,
+    }
+
+    @formatted
+    assert_neq_failed {
+        args: (left: impl Display, right: impl Display),
+        msg: format!("The assertion failed: `{left}` is equal to `{right}`."),
+        help: None,
+    }
+
+    @formatted
+    unsupported {
+        args: (construct: impl Display),
+        msg: format!("The interpreter does not support {construct}."),
+        help: Some("Run the program with snarkVM instead.".to_string()),
+    }
+
+    @formatted
+    cast_out_of_range {
+        args: (value: impl Display, type_: impl Display),
+        msg: format!("The value `{value}` cannot be cast to `{type_}`."),
+        help: None,
+    }
+
+    @formatted
+    index_out_of_bounds {
+        args: (index: impl Display, length: impl Display),
+        msg: format!("The index `{index}` is out of bounds for an array of length `{length}`."),
+        help: None,
+    }
+
+    @formatted
+    division_by_zero {
+        args: (),
+        msg: "Attempted to divide by zero.",
+        help: None,
+    }
+
+    @formatted
+    invalid_input {
+        args: (input: impl Display, type_: impl Display),
+        msg: format!("Failed to parse the input `{input}` as a value of type `{type_}`."),
+        help: None,
+    }
+
+    @formatted
+    invalid_literal {
+        args: (literal: impl Display),
+        msg: format!("Failed to evaluate the literal `{literal}`."),
+        help: None,
+    }
+
+    @backtraced
+    unknown_function {
+        args: (name: impl Display),
+        msg: format!("The function `{name}` does not exist."),
+        help: None,
+    }
+
+    @backtraced
+    wrong_number_of_inputs {
+        args: (name: impl Display, expected: impl Display, found: impl Display),
+        msg: format!("The function `{name}` expects {expected} input(s), but {found} were given."),
+        help: None,
+    }
+);
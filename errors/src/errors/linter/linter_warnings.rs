@@ -0,0 +1,58 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::create_messages;
+
+use std::fmt::Display;
+
+create_messages!(
+    /// LinterWarning enum that represents all the warnings for the linter.
+    LinterWarning,
+    code_mask: 4000i32,
+    code_prefix: "LIN",
+
+    /// For when a private input of a transition is never used.
+    @formatted
+    unconstrained_witness {
+        args: (name: impl Display),
+        msg: format!("The private input `{name}` is never used, so the prover can choose any value for it."),
+        help: Some("Use the input, or remove it. This is the `unconstrained_witness` lint.".to_string()),
+    }
+
+    /// For when `field` elements are ordered with `<`, `<=`, `>`, or `>=`.
+    @formatted
+    field_comparison {
+        args: (),
+        msg: "Ordering `field` elements compares their representatives below the modulus, which arithmetic wraps around, and decomposes both into bits.",
+        help: Some("Compare integers instead, if the values are bounded. This is the `field_comparison` lint.".to_string()),
+    }
+
+    /// For when an assertion only involves literals.
+    @formatted
+    constant_assertion {
+        args: (),
+        msg: "This assertion only involves constants, so it either always holds or always fails.",
+        help: Some("Assert something about the inputs instead. This is the `constant_assertion` lint.".to_string()),
+    }
+
+    /// For when a value of a type with few values is hashed, so that the hash can be inverted by trying them all.
+    @formatted
+    small_domain_hash {
+        args: (type_: impl Display),
+        msg: format!("Hashing a `{type_}` hides nothing, since every `{type_}` can be hashed to find the one that was used."),
+        help: Some("Hash the value together with a random salt, or commit to it with a `commit_to_*` function. This is the `small_domain_hash` lint.".to_string()),
+    }
+);
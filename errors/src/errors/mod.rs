@@ -15,7 +15,7 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 /// Contains the ASG error definitions.
-use crate::LeoMessageCode;
+use crate::{Diagnostic, LeoMessageCode};
 
 /// Contains the AST error definitions.
 pub mod ast;
@@ -37,6 +37,14 @@ pub use self::flattener::*;
 pub mod input;
 pub use self::input::*;
 
+/// Contains the Interpreter error definitions.
+pub mod interpreter;
+pub use self::interpreter::*;
+
+/// Contains the Linter warning definitions.
+pub mod linter;
+pub use self::linter::*;
+
 pub mod loop_unroller;
 pub use self::loop_unroller::*;
 
@@ -84,6 +92,9 @@ pub enum LeoError {
     /// Represents a Flatten Error in a Leo Error.
     #[error(transparent)]
     FlattenError(#[from] FlattenError),
+    /// Represents an Interpreter Error in a Leo Error.
+    #[error(transparent)]
+    InterpreterError(#[from] InterpreterError),
     /// Purely for just exiting with the correct status code and
     /// not re-displaying an error.
     #[error("")]
@@ -108,6 +119,7 @@ impl LeoError {
             TypeCheckerError(error) => error.error_code(),
             LoopUnrollerError(error) => error.error_code(),
             FlattenError(error) => error.error_code(),
+            InterpreterError(error) => error.error_code(),
             LastErrorCode(_) => unreachable!(),
             Anyhow(_) => unimplemented!(), // todo: implement error codes for snarkvm errors.
         }
@@ -127,19 +139,50 @@ impl LeoError {
             TypeCheckerError(error) => error.exit_code(),
             LoopUnrollerError(error) => error.exit_code(),
             FlattenError(error) => error.exit_code(),
+            InterpreterError(error) => error.exit_code(),
             LastErrorCode(code) => *code,
             Anyhow(_) => unimplemented!(), // todo: implement exit codes for snarkvm errors.
         }
     }
+
+    /// Returns the machine-readable form of the error,
+    /// or `None` if it is a `LastErrorCode`, which was already emitted.
+    pub fn diagnostic(&self) -> Option<Diagnostic> {
+        use LeoError::*;
+
+        match self {
+            AstError(error) => Some(error.diagnostic()),
+            CompilerError(error) => Some(error.diagnostic()),
+            CliError(error) => Some(error.diagnostic()),
+            InputError(error) => Some(error.diagnostic()),
+            ParserError(error) => Some(error.diagnostic()),
+            PackageError(error) => Some(error.diagnostic()),
+            TypeCheckerError(error) => Some(error.diagnostic()),
+            LoopUnrollerError(error) => Some(error.diagnostic()),
+            FlattenError(error) => Some(error.diagnostic()),
+            InterpreterError(error) => Some(error.diagnostic()),
+            LastErrorCode(_) => None,
+            Anyhow(error) => Some(Diagnostic::from_message(error)),
+        }
+    }
 }
 
 /// The LeoWarning type that contains all sub error types.
 /// This allows a unified error type throughout the Leo crates.
 #[derive(Debug, Error)]
 pub enum LeoWarning {
+    /// Represents a Linter Warning in a Leo Warning.
+    #[error(transparent)]
+    LinterWarning(#[from] LinterWarning),
+    /// Represents a Package Warning in a Leo Warning.
+    #[error(transparent)]
+    PackageWarning(#[from] PackageWarning),
     /// Represents an Parser Error in a Leo Error.
     #[error(transparent)]
     ParserWarning(#[from] ParserWarning),
+    /// Represents a Type Checker Warning in a Leo Warning.
+    #[error(transparent)]
+    TypeCheckerWarning(#[from] TypeCheckerWarning),
 }
 
 impl LeoWarning {
@@ -148,7 +191,22 @@ impl LeoWarning {
         use LeoWarning::*;
 
         match self {
+            LinterWarning(warning) => warning.warning_code(),
+            PackageWarning(warning) => warning.warning_code(),
             ParserWarning(warning) => warning.warning_code(),
+            TypeCheckerWarning(warning) => warning.warning_code(),
+        }
+    }
+
+    /// Returns the machine-readable form of the warning.
+    pub fn diagnostic(&self) -> Diagnostic {
+        use LeoWarning::*;
+
+        match self {
+            LinterWarning(warning) => warning.diagnostic(),
+            PackageWarning(warning) => warning.diagnostic(),
+            ParserWarning(warning) => warning.diagnostic(),
+            TypeCheckerWarning(warning) => warning.diagnostic(),
         }
     }
 }
@@ -17,3 +17,7 @@
 /// This module contains the Package error definitions.
 pub mod package_errors;
 pub use self::package_errors::*;
+
+/// This module contains the Package warning definitions.
+pub mod package_warnings;
+pub use self::package_warnings::*;
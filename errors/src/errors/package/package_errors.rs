@@ -313,4 +313,28 @@ create_messages!(
         msg: format!("IO error env file from the provided file path - {error}"),
         help: None,
     }
+
+    /// For when the `leo_version` of a package is not a version.
+    @backtraced
+    invalid_leo_version {
+        args: (package: impl Display, version: impl Display),
+        msg: format!("`{package}` has an invalid `leo_version` of `{version}` in `program.json`"),
+        help: Some("Set `leo_version` to a version such as `1.10.0`, or remove it.".to_string()),
+    }
+
+    /// For when an input file would be overwritten.
+    @backtraced
+    input_file_already_exists {
+        args: (path: impl Debug),
+        msg: format!("input file `{path:?}` already exists"),
+        help: Some("Use `--force` to overwrite it.".to_string()),
+    }
+
+    /// For when the fields that Leo reads from a manifest file have invalid values.
+    @backtraced
+    invalid_manifest {
+        args: (path: impl Debug, error: impl Display),
+        msg: format!("Invalid manifest file `{path:?}`: {error}"),
+        help: Some("Set `leo_version` to a version such as `1.10.0`, and `max_constraints` to an integer.".to_string()),
+    }
 );
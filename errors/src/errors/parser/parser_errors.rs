@@ -298,4 +298,11 @@ create_messages!(
         msg: format!("An array {kind} must have at least one element."),
         help: None,
     }
+
+    @backtraced
+    formatter_failed {
+        args: (reason: impl Display),
+        msg: format!("The formatted program {reason}, so the program was left unformatted."),
+        help: Some("This is a bug in the formatter. Please report it along with the program that failed to format.".to_string()),
+    }
 );
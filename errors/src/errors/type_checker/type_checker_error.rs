@@ -176,21 +176,21 @@ create_messages!(
     /// Attempted to define more that one struct member with the same name.
     @formatted
     duplicate_struct_member {
-        args: (struct_: impl Display),
+        args: (struct_: impl Display, member: impl Display),
         msg: format!(
-            "Struct {struct_} defined with more than one member with the same name."
+            "Struct {struct_} defined with more than one member named `{member}`."
         ),
-        help: None,
+        help: Some(format!("Rename or remove this definition of `{member}`.")),
     }
 
     /// Attempted to define more that one record variable with the same name.
     @formatted
     duplicate_record_variable {
-        args: (record: impl Display),
+        args: (record: impl Display, variable: impl Display),
         msg: format!(
-            "Record {record} defined with more than one variable with the same name."
+            "Record {record} defined with more than one variable named `{variable}`."
         ),
-        help: None,
+        help: Some(format!("Rename or remove this definition of `{variable}`.")),
     }
 
     /// Attempted to access an invalid struct.
@@ -372,7 +372,7 @@ create_messages!(
     transition_function_inputs_cannot_be_const {
         args: (),
         msg: format!("Transition functions cannot have constant inputs."),
-        help: None,
+        help: Some("Provide a `constant` value for this input in the input file to compile it into the program.".to_string()),
     }
 
     @formatted
@@ -712,4 +712,46 @@ create_messages!(
         msg: format!("An array cannot have a record as an element type"),
         help: None,
     }
+
+    @formatted
+    missing_return_in_branch {
+        args: (),
+        msg: format!("Function must return a value, but this branch does not return."),
+        help: Some("Add a `return` statement to the end of this branch, or after the conditional statement.".to_string()),
+    }
+
+    @formatted
+    mismatched_integer_types {
+        args: (left: impl Display, right: impl Display),
+        msg: format!("Expected operands of the same integer type, but found `{left}` and `{right}`."),
+        help: Some(format!("Add an explicit cast, such as `as {left}` or `as {right}`, so that both operands have the same type.")),
+    }
+
+    @formatted
+    test_annotation_on_non_transition {
+        args: (),
+        msg: format!("Only transitions can be annotated with `@test`."),
+        help: Some("Tests are run like transitions, so declare this function with `transition`.".to_string()),
+    }
+
+    @formatted
+    unknown_test_argument {
+        args: (argument: impl Display),
+        msg: format!("Unknown argument to `@test`: `{argument}`."),
+        help: Some("The only supported argument is `should_fail`, e.g. `@test(should_fail)`.".to_string()),
+    }
+
+    @formatted
+    max_constraints_annotation_on_non_transition {
+        args: (),
+        msg: format!("Only transitions can be annotated with `@max_constraints`."),
+        help: Some("Functions and inline functions are part of the circuits of the transitions that call them, so declare the budget on those transitions.".to_string()),
+    }
+
+    @formatted
+    invalid_max_constraints_argument {
+        args: (),
+        msg: format!("`@max_constraints` takes a single integer argument."),
+        help: Some("Declare the maximum number of constraints of the transition, e.g. `@max_constraints(100_000)`.".to_string()),
+    }
 );
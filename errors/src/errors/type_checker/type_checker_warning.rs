@@ -0,0 +1,66 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::create_messages;
+
+use std::fmt::Display;
+
+create_messages!(
+    /// TypeCheckerWarning enum that represents all the warnings for the type checking pass.
+    TypeCheckerWarning,
+    code_mask: 2000i32,
+    code_prefix: "TYC",
+
+    /// For when a variable is defined but never read.
+    @formatted
+    unused_variable {
+        args: (name: impl Display),
+        msg: format!("The variable `{name}` is never used."),
+        help: Some("Remove the definition, or use the variable.".to_string()),
+    }
+
+    /// For when an imported program is never referenced.
+    @formatted
+    unused_import {
+        args: (name: impl Display),
+        msg: format!("The imported program `{name}` is never used."),
+        help: Some("Remove the import statement.".to_string()),
+    }
+
+    /// For when the condition of a conditional statement is a boolean literal.
+    @formatted
+    constant_condition {
+        args: (value: impl Display),
+        msg: format!("This condition is always `{value}`."),
+        help: Some("Remove the conditional statement, or check that the condition is correct.".to_string()),
+    }
+
+    /// For when a comparison has the same value for every input.
+    @formatted
+    constant_comparison {
+        args: (value: impl Display),
+        msg: format!("This comparison is always `{value}`."),
+        help: Some("Check that the operands and the operator are correct.".to_string()),
+    }
+
+    /// For when an assertion can never fail.
+    @formatted
+    trivial_assertion {
+        args: (),
+        msg: format!("This assertion always holds."),
+        help: Some("Remove the assertion, or check that the asserted expression is correct.".to_string()),
+    }
+);
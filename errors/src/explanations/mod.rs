@@ -0,0 +1,183 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+// To explain a code, add a `CODE.md` file to this directory with guidance on how to fix the message, a minimal
+// program that causes it, and what compiling it reports, and add the file to `example`.
+//
+// `codes.txt` records the code of every message. Codes are assigned in the order of the messages, so new
+// messages are added at the end of their type, and the codes that they are assigned are added to `codes.txt`.
+
+use crate::{
+    AstError,
+    CliError,
+    CompilerError,
+    FlattenError,
+    InputError,
+    InterpreterError,
+    LinterWarning,
+    LoopUnrollerError,
+    PackageError,
+    PackageWarning,
+    ParserError,
+    ParserWarning,
+    TypeCheckerError,
+    TypeCheckerWarning,
+};
+
+/// The explanation of an error or warning code.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Explanation {
+    /// The code, e.g. `ETYC0372003`.
+    pub code: String,
+    /// The name of the message, e.g. `type_should_be`.
+    pub name: &'static str,
+    /// A minimal program that causes the message, and what compiling it reports, if one has been written.
+    pub example: Option<&'static str>,
+}
+
+/// Returns the code and the name of every error and warning message.
+pub fn message_codes() -> Vec<(String, &'static str)> {
+    [
+        AstError::message_codes(),
+        CliError::message_codes(),
+        CompilerError::message_codes(),
+        FlattenError::message_codes(),
+        InputError::message_codes(),
+        InterpreterError::message_codes(),
+        LinterWarning::message_codes(),
+        LoopUnrollerError::message_codes(),
+        PackageError::message_codes(),
+        PackageWarning::message_codes(),
+        ParserError::message_codes(),
+        ParserWarning::message_codes(),
+        TypeCheckerError::message_codes(),
+        TypeCheckerWarning::message_codes(),
+    ]
+    .concat()
+}
+
+/// Returns the explanation of the code, ignoring its case, or `None` if no message has the code.
+pub fn explain(code: &str) -> Option<Explanation> {
+    let code = code.to_uppercase();
+    let (code, name) = message_codes().into_iter().find(|(message_code, _)| *message_code == code)?;
+    let example = example(&code);
+    Some(Explanation { code, name, example })
+}
+
+/// Returns the example of the code, if one has been written.
+fn example(code: &str) -> Option<&'static str> {
+    let example = match code {
+        "EAST0372006" => include_str!("EAST0372006.md"),
+        "EAST0372007" => include_str!("EAST0372007.md"),
+        "EAST0372008" => include_str!("EAST0372008.md"),
+        "EAST0372009" => include_str!("EAST0372009.md"),
+        "ELUN0379000" => include_str!("ELUN0379000.md"),
+        "ELUN0379001" => include_str!("ELUN0379001.md"),
+        "EPAR0370004" => include_str!("EPAR0370004.md"),
+        "EPAR0370005" => include_str!("EPAR0370005.md"),
+        "EPAR0370009" => include_str!("EPAR0370009.md"),
+        "EPAR0370016" => include_str!("EPAR0370016.md"),
+        "EPAR0370017" => include_str!("EPAR0370017.md"),
+        "EPAR0370021" => include_str!("EPAR0370021.md"),
+        "EPAR0370029" => include_str!("EPAR0370029.md"),
+        "EPAR0370031" => include_str!("EPAR0370031.md"),
+        "EPAR0370032" => include_str!("EPAR0370032.md"),
+        "ETYC0372000" => include_str!("ETYC0372000.md"),
+        "ETYC0372002" => include_str!("ETYC0372002.md"),
+        "ETYC0372003" => include_str!("ETYC0372003.md"),
+        "ETYC0372005" => include_str!("ETYC0372005.md"),
+        "ETYC0372006" => include_str!("ETYC0372006.md"),
+        "ETYC0372007" => include_str!("ETYC0372007.md"),
+        "ETYC0372008" => include_str!("ETYC0372008.md"),
+        "ETYC0372017" => include_str!("ETYC0372017.md"),
+        "ETYC0372019" => include_str!("ETYC0372019.md"),
+        "ETYC0372020" => include_str!("ETYC0372020.md"),
+        "ETYC0372021" => include_str!("ETYC0372021.md"),
+        "ETYC0372024" => include_str!("ETYC0372024.md"),
+        "ETYC0372025" => include_str!("ETYC0372025.md"),
+        "ETYC0372026" => include_str!("ETYC0372026.md"),
+        "ETYC0372029" => include_str!("ETYC0372029.md"),
+        "ETYC0372030" => include_str!("ETYC0372030.md"),
+        "ETYC0372032" => include_str!("ETYC0372032.md"),
+        "ETYC0372035" => include_str!("ETYC0372035.md"),
+        "ETYC0372036" => include_str!("ETYC0372036.md"),
+        "ETYC0372038" => include_str!("ETYC0372038.md"),
+        "ETYC0372039" => include_str!("ETYC0372039.md"),
+        "ETYC0372042" => include_str!("ETYC0372042.md"),
+        "ETYC0372043" => include_str!("ETYC0372043.md"),
+        "ETYC0372044" => include_str!("ETYC0372044.md"),
+        "ETYC0372045" => include_str!("ETYC0372045.md"),
+        "ETYC0372047" => include_str!("ETYC0372047.md"),
+        "ETYC0372048" => include_str!("ETYC0372048.md"),
+        "ETYC0372050" => include_str!("ETYC0372050.md"),
+        "ETYC0372052" => include_str!("ETYC0372052.md"),
+        "ETYC0372054" => include_str!("ETYC0372054.md"),
+        "ETYC0372055" => include_str!("ETYC0372055.md"),
+        "ETYC0372056" => include_str!("ETYC0372056.md"),
+        "ETYC0372061" => include_str!("ETYC0372061.md"),
+        "ETYC0372062" => include_str!("ETYC0372062.md"),
+        "ETYC0372063" => include_str!("ETYC0372063.md"),
+        "ETYC0372064" => include_str!("ETYC0372064.md"),
+        "ETYC0372065" => include_str!("ETYC0372065.md"),
+        "ETYC0372067" => include_str!("ETYC0372067.md"),
+        "ETYC0372071" => include_str!("ETYC0372071.md"),
+        "ETYC0372072" => include_str!("ETYC0372072.md"),
+        "ETYC0372074" => include_str!("ETYC0372074.md"),
+        "ETYC0372076" => include_str!("ETYC0372076.md"),
+        "ETYC0372077" => include_str!("ETYC0372077.md"),
+        "ETYC0372084" => include_str!("ETYC0372084.md"),
+        "ETYC0372085" => include_str!("ETYC0372085.md"),
+        "ETYC0372087" => include_str!("ETYC0372087.md"),
+        _ => return None,
+    };
+    Some(example)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::{collections::HashSet, fs};
+
+    #[test]
+    fn codes_are_unique() {
+        let codes = message_codes();
+        let unique = codes.iter().map(|(code, _)| code).collect::<HashSet<_>>();
+        assert_eq!(codes.len(), unique.len());
+    }
+
+    #[test]
+    fn codes_are_stable() {
+        let codes = message_codes().iter().map(|(code, name)| format!("{code} {name}\n")).collect::<String>();
+        assert_eq!(codes, include_str!("codes.txt"), "a message was added before the end of its type, or removed");
+    }
+
+    #[test]
+    fn examples_are_of_known_codes() {
+        let directory = concat!(env!("CARGO_MANIFEST_DIR"), "/src/explanations");
+        for entry in fs::read_dir(directory).unwrap() {
+            let path = entry.unwrap().path();
+            if path.extension().map_or(true, |extension| extension != "md") {
+                continue;
+            }
+            let code = path.file_stem().unwrap().to_str().unwrap();
+            let explanation = explain(code).unwrap_or_else(|| panic!("`{code}.md` explains an unknown code"));
+            let example = explanation.example.unwrap_or_else(|| panic!("`{code}.md` is not in `example`"));
+            assert_eq!(example, fs::read_to_string(&path).unwrap());
+            assert!(example.contains(&format!("[{code}]")), "`{code}.md` does not report its code");
+        }
+    }
+}
@@ -32,3 +32,6 @@ pub mod emitter;
 /// Contains the errors and warnings for the Leo lang.
 pub mod errors;
 pub use self::errors::*;
+
+/// Contains the extended explanations of the error and warning codes.
+pub mod explanations;
@@ -15,11 +15,11 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::cli::{commands::*, context::*, helpers::*};
-use leo_errors::Result;
+use leo_errors::{emitter::JsonEmitter, LeoError, Result};
 
 use clap::Parser;
 use colored::Colorize;
-use std::{path::PathBuf, process::exit};
+use std::{path::PathBuf, process::exit, str::FromStr};
 
 /// CLI Arguments entry point - includes global parameters and subcommands
 #[derive(Parser, Debug)]
@@ -36,6 +36,15 @@ pub struct CLI {
 
     #[clap(long, global = true, help = "Optional path to Leo program root folder")]
     path: Option<PathBuf>,
+
+    #[clap(
+        long,
+        global = true,
+        help = "Write errors and warnings as `human` readable text, or as a `json` object per line",
+        default_value = "human",
+        value_parser = MessageFormat::from_str
+    )]
+    message_format: MessageFormat,
 }
 
 ///Leo compiler and package manager
@@ -56,6 +65,11 @@ enum Commands {
         #[clap(subcommand)]
         command: Example,
     },
+    #[clap(about = "Create a new Leo package from a template in a new directory")]
+    Init {
+        #[clap(flatten)]
+        command: Init,
+    },
     #[clap(about = "Compile the current package as a program")]
     Build {
         #[clap(flatten)]
@@ -66,21 +80,76 @@ enum Commands {
         #[clap(flatten)]
         command: Clean,
     },
+    #[clap(about = "Generate the input file of the current package")]
+    Input {
+        #[clap(subcommand)]
+        command: Input,
+    },
     #[clap(about = "Run a program with input variables")]
     Run {
         #[clap(flatten)]
         command: Run,
     },
+    #[clap(about = "Type check and evaluate Leo statements and expressions interactively")]
+    Repl {
+        #[clap(flatten)]
+        command: Repl,
+    },
+    #[clap(about = "Run the `@test` transitions of the current package")]
+    Test {
+        #[clap(flatten)]
+        command: Test,
+    },
     #[clap(about = "Execute a program with input variables")]
     Execute {
         #[clap(flatten)]
         command: Execute,
     },
+    #[clap(about = "Format the Leo source files of the current package")]
+    Fmt {
+        #[clap(flatten)]
+        command: Fmt,
+    },
+    #[clap(about = "Lint the current package for zero-knowledge pitfalls, with the rules set in `leo.toml`")]
+    Lint {
+        #[clap(flatten)]
+        command: Lint,
+    },
+    #[clap(about = "Generate the API documentation of the current package from its doc comments")]
+    Doc {
+        #[clap(flatten)]
+        command: Doc,
+    },
+    #[clap(about = "Benchmark the synthesis, setup, proving, and verification of a transition")]
+    Bench {
+        #[clap(flatten)]
+        command: Bench,
+    },
+    #[clap(about = "Prove a transition, and write the proof, its public inputs, and its verifying keys to files")]
+    Prove {
+        #[clap(flatten)]
+        command: Prove,
+    },
+    #[clap(about = "Verify a proof that was written by `leo prove`")]
+    Verify {
+        #[clap(flatten)]
+        command: Verify,
+    },
+    #[clap(about = "Explain an error or warning code, with an example program that causes it")]
+    Explain {
+        #[clap(flatten)]
+        command: Explain,
+    },
     #[clap(about = "Update the Leo CLI")]
     Update {
         #[clap(flatten)]
         command: Update,
     },
+    #[clap(about = "Rebuild the package whenever its source, import, or input files change")]
+    Watch {
+        #[clap(flatten)]
+        command: Watch,
+    },
 }
 
 pub fn handle_error<T>(res: Result<T>) -> T {
@@ -105,9 +174,10 @@ pub fn run_with_args(cli: CLI) -> Result<()> {
 
     // Get custom root folder and create context for it.
     // If not specified, default context will be created in cwd.
-    let context = handle_error(Context::new(cli.path));
+    let message_format = cli.message_format;
+    let context = handle_error(Context::new(cli.path, message_format));
 
-    match cli.command {
+    let result = match cli.command {
         Commands::Account { command } => command.try_execute(context),
         Commands::New { command } => command.try_execute(context),
         Commands::Build { command } => {
@@ -128,8 +198,33 @@ pub fn run_with_args(cli: CLI) -> Result<()> {
         }
         Commands::Clean { command } => command.try_execute(context),
         Commands::Example { command } => command.try_execute(context),
+        Commands::Init { command } => command.try_execute(context),
+        Commands::Input { command } => command.try_execute(context),
         Commands::Run { command } => command.try_execute(context),
+        Commands::Repl { command } => command.try_execute(context),
+        Commands::Test { command } => command.try_execute(context),
         Commands::Execute { command } => command.try_execute(context),
+        Commands::Fmt { command } => command.try_execute(context),
+        Commands::Lint { command } => command.try_execute(context),
+        Commands::Doc { command } => command.try_execute(context),
+        Commands::Bench { command } => command.try_execute(context),
+        Commands::Prove { command } => command.try_execute(context),
+        Commands::Verify { command } => command.try_execute(context),
+        Commands::Explain { command } => command.try_execute(context),
         Commands::Update { command } => command.try_execute(context),
+        Commands::Watch { command } => command.try_execute(context),
+    };
+
+    // With JSON messages, write the error that stopped the command as a diagnostic too,
+    // so that it is not printed again as text when exiting with its code.
+    match (result, message_format) {
+        (Err(err), MessageFormat::Json) => match err.diagnostic() {
+            Some(diagnostic) => {
+                JsonEmitter::emit_diagnostic(&diagnostic);
+                Err(LeoError::LastErrorCode(err.exit_code()))
+            }
+            None => Err(err),
+        },
+        (result, _) => result,
     }
 }
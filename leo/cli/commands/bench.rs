@@ -0,0 +1,253 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{prove::genesis_query, *};
+
+use leo_span::Symbol;
+
+use snarkvm::{
+    circuit::AleoV0,
+    cli::helpers::dotenv_private_key,
+    package::Package,
+    prelude::{Assignments, CallStack, Identifier, Locator, PrivateKey, StackExecute, Value},
+};
+
+use std::{
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+/// Benchmark the synthesis, setup, proving, and verification of a transition of the program.
+#[derive(Parser, Debug)]
+pub struct Bench {
+    #[clap(name = "NAME", help = "The name of the transition to benchmark.", default_value = "main")]
+    name: String,
+
+    #[clap(name = "INPUTS", help = "The inputs to the transition. If none are provided, the input file is used.")]
+    inputs: Vec<String>,
+
+    #[clap(long, help = "The number of times to run the benchmark.", default_value = "5")]
+    runs: usize,
+
+    #[clap(
+        long,
+        help = "The format of the results. With `json`, the results are also written to `outputs/bench/NAME.bench.json`.",
+        default_value = "text",
+        value_parser = ["text", "json"]
+    )]
+    output_format: String,
+
+    #[clap(flatten)]
+    pub(crate) compiler_options: BuildOptions,
+}
+
+/// The measurements of a single run of the benchmark.
+struct Sample {
+    /// The number of constraints of the transition's circuit, excluding those of its request and response.
+    constraints: u64,
+    /// The time to synthesize the circuit.
+    synthesis: Duration,
+    /// The time to synthesize the proving and verifying keys.
+    setup: Duration,
+    /// The time to execute the transition and prove the execution.
+    proving: Duration,
+    /// The time to verify the execution.
+    verification: Duration,
+}
+
+impl Command for Bench {
+    type Input = <Build as Command>::Output;
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Leo")
+    }
+
+    fn prelude(&self, context: Context) -> Result<Self::Input> {
+        (Build { options: self.compiler_options.clone() }).execute(context)
+    }
+
+    fn apply(self, context: Context, input: Self::Input) -> Result<Self::Output> {
+        // If input values are provided, then benchmark the transition with those inputs.
+        // Otherwise, use the input file.
+        let (input_ast, structs, functions) = input;
+        let inputs = match (self.inputs.is_empty(), input_ast, functions.get(&Symbol::intern(&self.name))) {
+            (true, Some(input_ast), Some(function)) => {
+                input_ast.program_inputs(function, None, structs, &context.handler())?
+            }
+            (true, ..) => Vec::new(),
+            (false, ..) => self.inputs,
+        };
+
+        // Open the Leo build/ directory
+        let path = context.dir()?;
+        let build_directory = BuildDirectory::open(&path)?;
+
+        // Change the cwd to the Leo build/ directory to run the program.
+        std::env::set_current_dir(&build_directory)
+            .map_err(|err| PackageError::failed_to_set_cwd(build_directory.display(), err))?;
+
+        // Unset the Leo panic hook
+        let _ = std::panic::take_hook();
+
+        let package = Package::<CurrentNetwork>::open(&build_directory).map_err(CliError::failed_to_execute_bench)?;
+        let private_key = dotenv_private_key().map_err(CliError::failed_to_execute_bench)?;
+        let function_name = Identifier::from_str(&self.name).map_err(CliError::failed_to_execute_bench)?;
+        let inputs = inputs
+            .iter()
+            .map(|input| Value::from_str(input))
+            .collect::<snarkvm::prelude::Result<Vec<_>>>()
+            .map_err(CliError::failed_to_execute_bench)?;
+
+        let mut samples = Vec::with_capacity(self.runs);
+        for run in 1..=self.runs {
+            tracing::info!("Run {run} of {}", self.runs);
+            let sample =
+                bench(&package, &private_key, function_name, &inputs).map_err(CliError::failed_to_execute_bench)?;
+            samples.push(sample);
+        }
+
+        let constraints = samples.first().map(|sample| sample.constraints).unwrap_or_default();
+        let timings: [(&str, Vec<Duration>); 4] = [
+            ("synthesis", samples.iter().map(|sample| sample.synthesis).collect()),
+            ("setup", samples.iter().map(|sample| sample.setup).collect()),
+            ("proving", samples.iter().map(|sample| sample.proving).collect()),
+            ("verification", samples.iter().map(|sample| sample.verification).collect()),
+        ];
+
+        tracing::info!("📊 {} over {} run(s)", package.program_id().to_string().bold(), self.runs);
+        tracing::info!(" • constraints: {constraints}");
+        for (name, durations) in timings.iter() {
+            let summary = Summary::new(durations);
+            tracing::info!(
+                " • {name}: mean {:.2}ms, median {:.2}ms, min {:.2}ms, max {:.2}ms, std dev {:.2}ms",
+                summary.mean,
+                summary.median,
+                summary.min,
+                summary.max,
+                summary.std_dev
+            );
+        }
+
+        if self.output_format == "json" {
+            let mut json = serde_json::json!({
+                "program": package.program_id().to_string(),
+                "function": self.name,
+                "runs": self.runs,
+                "constraints": constraints,
+            });
+            for (name, durations) in timings.iter() {
+                json[name] = Summary::new(durations).to_json(durations);
+            }
+
+            let outputs_path =
+                OutputsDirectory::create_for(&path, Artifact::Bench)?.join(format!("{}.bench.json", self.name));
+            std::fs::write(&outputs_path, serde_json::to_string_pretty(&json).expect("JSON values are serializable"))
+                .map_err(|e| {
+                    CliError::failed_to_execute_bench(format!("failed to write `{}`: {e}", outputs_path.display()))
+                })?;
+            tracing::info!("✅ Wrote the results to {}", outputs_path.display());
+        }
+
+        Ok(())
+    }
+}
+
+/// Synthesizes, sets up, proves, and verifies the transition once.
+/// Each run starts from a new process, so that the keys are synthesized again.
+fn bench(
+    package: &Package<CurrentNetwork>,
+    private_key: &PrivateKey<CurrentNetwork>,
+    function_name: Identifier<CurrentNetwork>,
+    inputs: &[Value<CurrentNetwork>],
+) -> snarkvm::prelude::Result<Sample> {
+    let rng = &mut rand::thread_rng();
+    let program_id = package.program_id();
+    let process = package.get_process()?;
+
+    // Synthesize the circuit, without proving it.
+    let authorization = process.authorize::<AleoV0, _>(private_key, program_id, function_name, inputs.iter(), rng)?;
+    let assignments = Assignments::<CurrentNetwork>::default();
+    let call_stack = CallStack::PackageRun(vec![authorization.next()?], *private_key, assignments.clone());
+    let start = Instant::now();
+    process.get_stack(program_id)?.execute_function::<AleoV0>(call_stack, None)?;
+    let synthesis = start.elapsed();
+    let constraints = assignments.read().last().map(|(_, metrics)| metrics.num_function_constraints);
+
+    let start = Instant::now();
+    process.synthesize_key::<AleoV0, _>(program_id, &function_name, rng)?;
+    let setup = start.elapsed();
+
+    let query = genesis_query()?;
+    let locator = Locator::new(*program_id, function_name).to_string();
+
+    let authorization = process.authorize::<AleoV0, _>(private_key, program_id, function_name, inputs.iter(), rng)?;
+    let start = Instant::now();
+    let (_, mut trace) = process.execute::<AleoV0>(authorization)?;
+    trace.prepare(query)?;
+    let execution = trace.prove_execution::<AleoV0, _>(&locator, rng)?;
+    let proving = start.elapsed();
+
+    let start = Instant::now();
+    process.verify_execution(&execution)?;
+    let verification = start.elapsed();
+
+    Ok(Sample { constraints: constraints.unwrap_or_default(), synthesis, setup, proving, verification })
+}
+
+/// The statistical summary of the durations of a step across the runs, in milliseconds.
+struct Summary {
+    mean: f64,
+    median: f64,
+    min: f64,
+    max: f64,
+    std_dev: f64,
+}
+
+impl Summary {
+    fn new(durations: &[Duration]) -> Self {
+        let mut millis = durations.iter().map(|duration| duration.as_secs_f64() * 1000.0).collect::<Vec<_>>();
+        millis.sort_by(f64::total_cmp);
+
+        let count = millis.len().max(1) as f64;
+        let mean = millis.iter().sum::<f64>() / count;
+        let median = match millis.len() {
+            0 => 0.0,
+            len if len % 2 == 0 => (millis[len / 2 - 1] + millis[len / 2]) / 2.0,
+            len => millis[len / 2],
+        };
+        let variance = millis.iter().map(|value| (value - mean).powi(2)).sum::<f64>() / count;
+
+        Self {
+            mean,
+            median,
+            min: millis.first().copied().unwrap_or_default(),
+            max: millis.last().copied().unwrap_or_default(),
+            std_dev: variance.sqrt(),
+        }
+    }
+
+    fn to_json(&self, durations: &[Duration]) -> serde_json::Value {
+        serde_json::json!({
+            "mean_ms": self.mean,
+            "median_ms": self.median,
+            "min_ms": self.min,
+            "max_ms": self.max,
+            "std_dev_ms": self.std_dev,
+            "samples_ms": durations.iter().map(|duration| duration.as_secs_f64() * 1000.0).collect::<Vec<_>>(),
+        })
+    }
+}
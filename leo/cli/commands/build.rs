@@ -16,23 +16,41 @@
 
 use super::*;
 
-use leo_ast::{NodeBuilder, Struct};
+use leo_ast::{Function, Struct};
 use leo_compiler::{Compiler, CompilerOptions, InputAst, OutputOptions};
+use leo_errors::PackageWarning;
 use leo_package::{
     build::BuildDirectory,
     imports::ImportsDirectory,
     inputs::InputFile,
-    outputs::OutputsDirectory,
+    outputs::{Artifact, OutputsDirectory},
     source::SourceDirectory,
 };
-use leo_span::{symbol::with_session_globals, Symbol};
+use leo_span::Symbol;
 
 use snarkvm::{
+    circuit::AleoV0,
+    file::Manifest,
     package::Package,
-    prelude::{ProgramID, Testnet3},
+    prelude::{
+        Address,
+        Assignments,
+        CallStack,
+        Identifier,
+        Locator,
+        PrivateKey,
+        Process,
+        ProgramID,
+        Request,
+        StackExecute,
+        Testnet3,
+        ValueType,
+    },
 };
 
 use indexmap::IndexMap;
+use rand::{CryptoRng, Rng};
+use serde::Deserialize;
 use std::{
     io::Write,
     path::{Path, PathBuf},
@@ -41,7 +59,7 @@ use std::{
 impl From<BuildOptions> for CompilerOptions {
     fn from(options: BuildOptions) -> Self {
         let mut out_options = Self {
-            build: leo_compiler::BuildOptions { dce_enabled: options.enable_dce },
+            build: leo_compiler::BuildOptions { dce_enabled: options.enable_dce, tests_enabled: options.enable_tests },
             output: OutputOptions {
                 symbol_table_spans_enabled: options.enable_symbol_table_spans,
                 initial_symbol_table: options.enable_initial_symbol_table_snapshot,
@@ -82,7 +100,7 @@ pub struct Build {
 
 impl Command for Build {
     type Input = ();
-    type Output = (Option<InputAst>, IndexMap<Symbol, Struct>);
+    type Output = (Option<InputAst>, IndexMap<Symbol, Struct>, IndexMap<Symbol, Function>);
 
     fn log_span(&self) -> Span {
         tracing::span!(tracing::Level::INFO, "Leo")
@@ -100,17 +118,17 @@ impl Command for Build {
         let manifest = context.open_manifest()?;
         let program_id = manifest.program_id();
 
-        // Create the outputs directory.
-        let outputs_directory = OutputsDirectory::create(&package_path)?;
+        // Create the directory of the AST and symbol table snapshots.
+        let outputs_directory = OutputsDirectory::create_for(&package_path, Artifact::Ast)?;
 
         // Open the build directory.
         let build_directory = BuildDirectory::open(&package_path)?;
 
         // Initialize error handler
-        let handler = Handler::default();
+        let handler = context.handler();
 
-        // Initialize a node counter.
-        let node_builder = NodeBuilder::default();
+        // Check that the package targets a compatible version of Leo.
+        check_leo_version(&program_id.name().to_string(), manifest.path(), &handler)?;
 
         // Fetch paths to all .leo files in the source directory.
         let source_files = SourceDirectory::files(&package_path)?;
@@ -118,21 +136,44 @@ impl Command for Build {
         // Check the source files.
         SourceDirectory::check_files(&source_files)?;
 
+        // Locate the input file at `package_name.in`, or at `package_name.json` if there is none.
+        let input_file = InputFile::new(&program_id.name().to_string());
+        let input_file_path = input_file.setup_file_path(&package_path);
+        let json_input_file_path = input_file.setup_json_file_path(&package_path);
+
+        // Only consider the input file of the requested format, if any.
+        let use_in = self.options.input_format.as_deref() != Some("json");
+        let use_json = self.options.input_format.as_deref() != Some("in");
+        let input_file_path = if use_in && input_file_path.exists() {
+            Some(input_file_path.into_owned())
+        } else if use_json && json_input_file_path.exists() {
+            Some(json_input_file_path.into_owned())
+        } else {
+            None
+        };
+
         // Store all struct declarations made in the source files.
         let mut structs = IndexMap::new();
+        // Store all function declarations made in the source files.
+        let mut functions = IndexMap::new();
+        // Store the input file of the program, if there is one.
+        let mut input_ast = None;
 
         // Compile all .leo files into .aleo files.
         for file_path in source_files.into_iter() {
-            structs.extend(compile_leo_file(
+            let (file_structs, file_functions, file_input_ast) = compile_leo_file(
                 file_path,
-                &package_path,
+                input_file_path.as_deref(),
                 program_id,
                 &outputs_directory,
                 &build_directory,
                 &handler,
                 self.options.clone(),
                 false,
-            )?);
+            )?;
+            structs.extend(file_structs);
+            functions.extend(file_functions);
+            input_ast = input_ast.or(file_input_ast);
         }
 
         if !ImportsDirectory::is_empty(&package_path)? {
@@ -144,39 +185,39 @@ impl Command for Build {
 
             // Compile all .leo files into .aleo files.
             for file_path in import_files.into_iter() {
-                structs.extend(compile_leo_file(
-                    file_path,
-                    &package_path,
-                    program_id,
-                    &outputs_directory,
-                    &build_imports_directory,
-                    &handler,
-                    self.options.clone(),
-                    true,
-                )?);
+                // Check that the imported package targets a compatible version of Leo, if its manifest is present.
+                if let Some(manifest_path) = ImportsDirectory::manifest(&file_path) {
+                    let name = file_path.file_stem().unwrap_or_default().to_string_lossy();
+                    check_leo_version(&name, &manifest_path, &handler)?;
+                }
+
+                structs.extend(
+                    compile_leo_file(
+                        file_path,
+                        None,
+                        program_id,
+                        &outputs_directory,
+                        &build_imports_directory,
+                        &handler,
+                        self.options.clone(),
+                        true,
+                    )?
+                    .0,
+                );
             }
         }
 
-        // Load the input file at `package_name.in`
-        let input_file_path = InputFile::new(&manifest.program_id().name().to_string()).setup_file_path(&package_path);
-
-        // Parse the input file.
-        let input_ast = if input_file_path.exists() {
-            // Load the input file into the source map.
-            let input_sf = with_session_globals(|s| s.source_map.load_file(&input_file_path))
-                .map_err(|e| CompilerError::file_read_error(&input_file_path, e))?;
-
-            // TODO: This is a hack to notify the user that something is wrong with the input file. Redesign.
-            leo_parser::parse_input(&handler, &node_builder, &input_sf.src, input_sf.start_pos)
-                .map_err(|_e| println!("Warning: Failed to parse input file"))
-                .ok()
-        } else {
-            None
-        };
+        // If warnings are denied, then fail the build if any were emitted.
+        if self.options.deny_warnings && handler.warning_count() > 0 {
+            return Err(CliError::warnings_denied(handler.warning_count()).into());
+        }
 
         // `Package::open` checks that the build directory and that `main.aleo` and all imported files are well-formed.
         Package::<CurrentNetwork>::open(&build_directory).map_err(CliError::failed_to_execute_build)?;
 
+        // Check the constraint budgets of the program and of its transitions, if any are declared.
+        check_constraint_budgets(&manifest, &build_directory, &functions)?;
+
         // // Unset the Leo panic hook.
         // let _ = std::panic::take_hook();
         //
@@ -195,22 +236,23 @@ impl Command for Build {
         // // Log the result of the build
         // tracing::info!("{}", result);
 
-        Ok((input_ast, structs))
+        Ok((input_ast, structs, functions))
     }
 }
 
-/// Compiles a Leo file in the `src/` directory.
+/// Compiles a Leo file in the `src/` directory, with the input file at `input_file_path`, if any.
+/// Returns the struct declarations visible to the program, the functions it declares, and its input file.
 #[allow(clippy::too_many_arguments)]
 fn compile_leo_file(
     file_path: PathBuf,
-    _package_path: &Path,
+    input_file_path: Option<&Path>,
     program_id: &ProgramID<Testnet3>,
     outputs: &Path,
     build: &Path,
     handler: &Handler,
     options: BuildOptions,
     is_import: bool,
-) -> Result<IndexMap<Symbol, Struct>> {
+) -> Result<(IndexMap<Symbol, Struct>, IndexMap<Symbol, Function>, Option<InputAst>)> {
     // Construct the Leo file name with extension `foo.leo`.
     let file_name =
         file_path.file_name().and_then(|name| name.to_str()).ok_or_else(PackageError::failed_to_get_file_name)?;
@@ -239,8 +281,18 @@ fn compile_leo_file(
         Some(options.into()),
     );
 
+    // Parse the Leo program.
+    compiler.parse_program()?;
+
+    // Parse the input file of the program, which also provides the values of its constant inputs.
+    let input_ast = match input_file_path {
+        Some(input_file_path) => parse_input_file(&compiler, input_file_path, handler)?,
+        None => None,
+    };
+    compiler.input_ast = input_ast.clone();
+
     // Compile the Leo program into Aleo instructions.
-    let (symbol_table, instructions) = compiler.compile()?;
+    let (symbol_table, instructions) = compiler.compile_parsed_program()?;
 
     // Write the instructions.
     std::fs::File::create(&aleo_file_path)
@@ -249,5 +301,209 @@ fn compile_leo_file(
         .map_err(CliError::failed_to_load_instructions)?;
 
     tracing::info!("✅ Compiled '{}' into Aleo instructions", file_name);
-    Ok(symbol_table.structs)
+    // Note that the compiler passes do not modify the signatures of the functions, except to remove constant inputs.
+    let functions =
+        compiler.ast.ast.program_scopes.into_values().flat_map(|program_scope| program_scope.functions).collect();
+
+    Ok((symbol_table.structs, functions, input_ast))
+}
+
+/// Parses the input file at `input_file_path`, which is read as JSON if it has the `json` extension.
+/// The values in a JSON input file take their types from the inputs of the program parsed by `compiler`.
+fn parse_input_file(compiler: &Compiler, input_file_path: &Path, handler: &Handler) -> Result<Option<InputAst>> {
+    if input_file_path.extension().map_or(false, |extension| extension == "json") {
+        let json =
+            std::fs::read_to_string(input_file_path).map_err(|e| CompilerError::file_read_error(input_file_path, e))?;
+        let json = serde_json::from_str(&json).map_err(InputError::invalid_json_input)?;
+
+        let program = &compiler.ast.ast;
+        let functions =
+            program.program_scopes.values().flat_map(|program_scope| program_scope.functions.iter().cloned()).collect();
+        // Inputs may also be structs and records of imported programs.
+        let structs = program
+            .imports
+            .values()
+            .flat_map(|(import, _)| import.program_scopes.values())
+            .chain(program.program_scopes.values())
+            .flat_map(|program_scope| program_scope.structs.iter().cloned())
+            .collect();
+        Ok(Some(leo_ast::json_input_to_ast(&json, &functions, &structs, compiler.node_builder())?))
+    } else {
+        Ok(Some(leo_parser::parse_input_file(handler, compiler.node_builder(), input_file_path)?))
+    }
+}
+
+/// The fields of `program.json` that Leo reads, in addition to those that snarkVM reads.
+#[derive(Debug, Deserialize)]
+pub(crate) struct ManifestFields {
+    /// The version of Leo that the package targets, e.g. `1.10.0`.
+    pub(crate) leo_version: Option<String>,
+    /// The maximum number of constraints of the program's transitions together.
+    pub(crate) max_constraints: Option<u64>,
+}
+
+impl ManifestFields {
+    /// Reads the fields from the manifest at `manifest_path`, failing if they have invalid values.
+    pub(crate) fn read(manifest_path: &Path) -> Result<Self> {
+        let manifest = std::fs::read_to_string(manifest_path).map_err(PackageError::failed_to_open_manifest)?;
+        Ok(serde_json::from_str(&manifest).map_err(|error| PackageError::invalid_manifest(manifest_path, error))?)
+    }
+}
+
+/// Warns if the package `name`, whose manifest is at `manifest_path`, targets a version of Leo that is not
+/// compatible with this one. Versions with the same major and minor version are compatible.
+fn check_leo_version(name: &str, manifest_path: &Path, handler: &Handler) -> Result<()> {
+    let Some(version) = ManifestFields::read(manifest_path)?.leo_version else {
+        return Ok(());
+    };
+
+    let current = env!("CARGO_PKG_VERSION");
+    match major_minor(&version) {
+        None => return Err(PackageError::invalid_leo_version(name, version).into()),
+        Some(target) if Some(target) != major_minor(current) => {
+            handler.emit_warning(PackageWarning::incompatible_leo_version(name, version, current).into())
+        }
+        Some(_) => {}
+    }
+    Ok(())
+}
+
+/// Returns the major and minor version of a version such as `1.10.0`.
+fn major_minor(version: &str) -> Option<(u64, u64)> {
+    let mut parts = version.split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().ok()?;
+    // The patch version, if any, may have a pre-release suffix such as `0-beta`.
+    match parts.next() {
+        Some(patch) if !patch.starts_with(|c: char| c.is_ascii_digit()) => None,
+        _ => Some((major, minor)),
+    }
+}
+
+/// The number of largest contributors listed when a constraint budget is exceeded.
+const CONTRIBUTORS: usize = 5;
+
+/// Fails if a transition exceeds the budget declared with `@max_constraints`, or if the transitions together
+/// exceed the budget declared as `max_constraints` in `program.json`, reporting the largest contributors.
+/// The budget of a transition covers the transitions that it calls, while the budget of the program covers
+/// only its own transitions. The constraints are only estimated if a budget is declared.
+fn check_constraint_budgets(
+    manifest: &Manifest<CurrentNetwork>,
+    build_directory: &Path,
+    functions: &IndexMap<Symbol, Function>,
+) -> Result<()> {
+    let program_budget = ManifestFields::read(manifest.path())?.max_constraints;
+    let budgets = functions
+        .iter()
+        .filter_map(|(name, function)| Some((name.to_string(), function.max_constraints()?)))
+        .collect::<Vec<_>>();
+    if program_budget.is_none() && budgets.is_empty() {
+        return Ok(());
+    }
+
+    let estimates = estimate_constraints(build_directory).map_err(CliError::failed_to_execute_build)?;
+    let mut report = Vec::new();
+
+    for (name, budget) in budgets {
+        let calls = estimates.get(&name).cloned().unwrap_or_default();
+        let total = calls.iter().map(|(_, constraints)| constraints).sum::<u64>();
+        if total > budget {
+            report.push(format!(
+                "`{name}` has {total} constraints, over its budget of {budget}.\n{}",
+                contributors(calls)
+            ));
+        }
+    }
+
+    if let Some(budget) = program_budget {
+        // The transition itself is synthesized after the transitions it calls.
+        let transitions = estimates
+            .iter()
+            .filter(|(name, _)| !functions.get(&Symbol::intern(name)).is_some_and(Function::is_test))
+            .filter_map(|(_, calls)| calls.last().cloned())
+            .collect::<Vec<_>>();
+        let total = transitions.iter().map(|(_, constraints)| constraints).sum::<u64>();
+        if total > budget {
+            report.push(format!(
+                "The program has {total} constraints, over its budget of {budget}.\n{}",
+                contributors(transitions)
+            ));
+        }
+    }
+
+    match report.is_empty() {
+        true => Ok(()),
+        false => Err(CliError::constraint_budget_exceeded(report.join("\n")).into()),
+    }
+}
+
+/// Lists the transitions with the most constraints, e.g. `  • token.aleo/mint: 2020`.
+fn contributors(mut calls: Vec<(String, u64)>) -> String {
+    calls.sort_by(|(_, a), (_, b)| b.cmp(a));
+    calls
+        .iter()
+        .take(CONTRIBUTORS)
+        .map(|(locator, constraints)| format!("  • {locator}: {constraints}"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Returns the number of constraints of each transition of the program in the build directory, along with
+/// those of the transitions it calls, as pairs of a locator and a count, ending with the transition itself.
+/// Each transition is synthesized with sampled inputs, as when a deployment is verified, so the
+/// count is an estimate for transitions whose circuit depends on the values of their inputs.
+pub(crate) fn estimate_constraints(
+    build_directory: &Path,
+) -> snarkvm::prelude::Result<IndexMap<String, Vec<(String, u64)>>> {
+    let package = Package::<CurrentNetwork>::open(build_directory)?;
+    let process = package.get_process()?;
+
+    let rng = &mut rand::thread_rng();
+    let mut estimates = IndexMap::new();
+    for function_name in package.program().functions().keys() {
+        let assignments = synthesize_circuit(&process, package.program_id(), function_name, rng)?;
+        let calls = assignments
+            .read()
+            .iter()
+            .map(|(_, metrics)| {
+                (Locator::new(metrics.program_id, metrics.function_name).to_string(), metrics.num_function_constraints)
+            })
+            .collect();
+        estimates.insert(function_name.to_string(), calls);
+    }
+    Ok(estimates)
+}
+
+/// Synthesizes the circuit of the function with sampled inputs, as when a deployment is verified, and returns
+/// the assignments of the function and of the functions it calls, ending with the function itself.
+pub(crate) fn synthesize_circuit<R: Rng + CryptoRng>(
+    process: &Process<CurrentNetwork>,
+    program_id: &ProgramID<CurrentNetwork>,
+    function_name: &Identifier<CurrentNetwork>,
+    rng: &mut R,
+) -> snarkvm::prelude::Result<Assignments<CurrentNetwork>> {
+    let stack = process.get_stack(program_id)?;
+    let function = process.get_program(program_id)?.get_function(function_name)?;
+
+    let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+    let address = Address::try_from(&private_key)?;
+    let input_types = function.input_types();
+    let inputs = input_types
+        .iter()
+        .map(|input_type| match input_type {
+            ValueType::ExternalRecord(locator) => process.get_stack(locator.program_id())?.sample_value(
+                &address,
+                &ValueType::Record(*locator.resource()),
+                rng,
+            ),
+            _ => stack.sample_value(&address, input_type, rng),
+        })
+        .collect::<snarkvm::prelude::Result<Vec<_>>>()?;
+    let request = Request::sign(&private_key, *program_id, *function_name, inputs.into_iter(), &input_types, rng)?;
+
+    // Checking a deployment synthesizes the circuit without requiring it to be satisfied.
+    let assignments = Assignments::<CurrentNetwork>::default();
+    let call_stack = CallStack::CheckDeployment(vec![request], private_key, assignments.clone());
+    stack.execute_function::<AleoV0>(call_stack, None)?;
+    Ok(assignments)
 }
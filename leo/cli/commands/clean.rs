@@ -16,9 +16,18 @@
 
 use super::*;
 
+use std::str::FromStr;
+
 /// Clean outputs folder command
 #[derive(Parser, Debug)]
-pub struct Clean {}
+pub struct Clean {
+    #[clap(
+        name = "ARTIFACTS",
+        help = "The artifacts to remove, e.g. `keys` or `proofs`. If none are provided, the outputs and build directories are removed.",
+        value_parser = Artifact::from_str
+    )]
+    artifacts: Vec<Artifact>,
+}
 
 impl Command for Clean {
     type Input = ();
@@ -35,6 +44,15 @@ impl Command for Clean {
     fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
         let path = context.dir()?;
 
+        // Removes only the requested artifacts, if any.
+        if !self.artifacts.is_empty() {
+            for artifact in self.artifacts {
+                let artifact_path = OutputsDirectory::remove_artifact(&path, artifact)?;
+                tracing::info!("🧹 Cleaned the {artifact} artifacts {}", artifact_path.dimmed());
+            }
+            return Ok(());
+        }
+
         // Removes the outputs/ directory.
         let outputs_path = OutputsDirectory::remove(&path)?;
         tracing::info!("🧹 Cleaned the outputs directory {}", outputs_path.dimmed());
@@ -0,0 +1,327 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{build::estimate_constraints, *};
+
+use leo_ast::{Function, Mode, NodeBuilder, Output, Program, Struct, Variant};
+use leo_package::{
+    build::BUILD_DIRECTORY_NAME,
+    source::{MAIN_FILENAME, SOURCE_DIRECTORY_NAME},
+};
+use leo_parser::Comment;
+use leo_span::{source_map::FileName, span::BytePos, symbol::with_session_globals, Span as SourceSpan};
+
+use indexmap::IndexMap;
+use std::fs;
+
+/// Generate API documentation for the program from the `///` doc comments in its source.
+#[derive(Parser, Debug)]
+pub struct Doc {
+    #[clap(
+        long,
+        help = "The format of the documentation, which is written to `outputs/docs/PROGRAM.md` or `outputs/docs/PROGRAM.html`.",
+        default_value = "markdown",
+        value_parser = ["markdown", "html"]
+    )]
+    format: String,
+
+    #[clap(long, help = "Do not estimate the number of constraints of each transition.")]
+    no_constraints: bool,
+
+    #[clap(flatten)]
+    pub(crate) compiler_options: BuildOptions,
+}
+
+impl Command for Doc {
+    type Input = <Build as Command>::Output;
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Leo")
+    }
+
+    fn prelude(&self, context: Context) -> Result<Self::Input> {
+        // Building checks the program, and produces the bytecode that the constraints are estimated from.
+        (Build { options: self.compiler_options.clone() }).execute(context)
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        let package_path = context.dir()?;
+        let program_id = context.open_manifest()?.program_id().to_string();
+
+        let path = package_path.join(SOURCE_DIRECTORY_NAME).join(MAIN_FILENAME);
+        let source = fs::read_to_string(&path).map_err(|e| CompilerError::file_read_error(&path, e))?;
+        let file = with_session_globals(|s| s.source_map.new_source(&source, FileName::Real(path.clone())));
+        let (program, comments) =
+            leo_parser::parse_with_comments(&context.handler(), &NodeBuilder::default(), &file.src, file.start_pos)?;
+
+        let constraints = match self.no_constraints {
+            true => IndexMap::new(),
+            false => match estimate_constraints(&package_path.join(BUILD_DIRECTORY_NAME)) {
+                // The transition itself is synthesized after the transitions it calls.
+                Ok(estimates) => {
+                    estimates.into_iter().filter_map(|(name, calls)| Some((name, calls.last()?.1))).collect()
+                }
+                Err(error) => {
+                    tracing::warn!("Failed to estimate the constraints of the transitions: {error}");
+                    IndexMap::new()
+                }
+            },
+        };
+
+        let items = items(&program, &comments, &constraints);
+        let (documentation, extension) = match self.format.as_str() {
+            "html" => (html(&program_id, &items), "html"),
+            _ => (markdown(&program_id, &items), "md"),
+        };
+
+        let name = program_id.trim_end_matches(".aleo");
+        let path = OutputsDirectory::create_for(&package_path, Artifact::Docs)?.join(format!("{name}.{extension}"));
+        fs::write(&path, documentation).map_err(CliError::failed_to_write_file)?;
+        tracing::info!("✅ Wrote the documentation to {}", path.display());
+
+        Ok(())
+    }
+}
+
+/// A documented declaration of the program.
+struct Item {
+    /// The heading of the section that the item is listed in, e.g. `Transitions`.
+    section: &'static str,
+    /// The name of the item.
+    name: String,
+    /// The declaration of the item, without its body.
+    signature: String,
+    /// The doc comment on the item.
+    doc: String,
+    /// The declarations and doc comments of the members of a struct or record.
+    members: Vec<(String, String)>,
+    /// The estimated number of constraints of a transition.
+    constraints: Option<u64>,
+}
+
+/// The sections of the documentation, in the order they are written.
+const SECTIONS: [&str; 6] = ["Records", "Structs", "Constants", "Mappings", "Transitions", "Functions"];
+
+/// Returns the declarations of the program scope, in source order, along with their doc comments.
+/// Test functions are left out.
+fn items(program: &Program, comments: &[Comment], constraints: &IndexMap<String, u64>) -> Vec<Item> {
+    // Parsing guarantees that a program has exactly one program scope.
+    let scope = program.program_scopes.values().next().unwrap();
+
+    // A doc comment belongs to the next declaration, so the comments between the end of the previous
+    // declaration and the start of a declaration are its doc comment.
+    let mut ends = vec![scope.program_id.network.span.hi];
+    ends.extend(program.imports.values().map(|(_, span)| span.hi));
+    ends.extend(scope.consts.iter().map(|(_, declaration)| declaration.span.hi));
+    ends.extend(scope.mappings.iter().map(|(_, mapping)| mapping.span.hi));
+    ends.extend(scope.functions.iter().map(|(_, function)| function.span.hi));
+    for (_, struct_) in scope.structs.iter() {
+        ends.extend([struct_.span.hi, struct_.identifier.span.hi]);
+        ends.extend(struct_.members.iter().map(|member| member.span.hi));
+    }
+    let doc = |span: SourceSpan| doc_comment(comments, &ends, span.lo);
+
+    let mut items = Vec::new();
+    for (_, struct_) in scope.structs.iter() {
+        items.push((struct_.span, struct_item(struct_, &doc)));
+    }
+    for (name, declaration) in scope.consts.iter() {
+        items.push((declaration.span, Item {
+            section: "Constants",
+            name: name.to_string(),
+            signature: format!("const {name}: {} = {}", declaration.type_, declaration.value),
+            doc: doc(declaration.span),
+            members: Vec::new(),
+            constraints: None,
+        }));
+    }
+    for (name, mapping) in scope.mappings.iter() {
+        items.push((mapping.span, Item {
+            section: "Mappings",
+            name: name.to_string(),
+            signature: mapping.to_string(),
+            doc: doc(mapping.span),
+            members: Vec::new(),
+            constraints: None,
+        }));
+    }
+    for (name, function) in scope.functions.iter().filter(|(_, function)| !function.is_test()) {
+        items.push((function.span, Item {
+            section: match function.variant {
+                Variant::Transition => "Transitions",
+                Variant::Standard | Variant::Inline => "Functions",
+            },
+            name: name.to_string(),
+            signature: function_signature(function),
+            doc: doc(function.span),
+            members: Vec::new(),
+            constraints: constraints.get(&name.to_string()).copied(),
+        }));
+    }
+
+    items.sort_by_key(|(span, _)| span.lo);
+    items.into_iter().map(|(_, item)| item).collect()
+}
+
+fn struct_item(struct_: &Struct, doc: &impl Fn(SourceSpan) -> String) -> Item {
+    let members = struct_
+        .members
+        .iter()
+        .map(|member| {
+            let signature = match member.mode {
+                Mode::None => format!("{}: {}", member.identifier, member.type_),
+                mode => format!("{mode} {}: {}", member.identifier, member.type_),
+            };
+            (signature, doc(member.span))
+        })
+        .collect();
+
+    let keyword = if struct_.is_record { "record" } else { "struct" };
+    Item {
+        section: if struct_.is_record { "Records" } else { "Structs" },
+        name: struct_.identifier.to_string(),
+        signature: format!("{keyword} {}", struct_.identifier),
+        doc: doc(struct_.span),
+        members,
+        constraints: None,
+    }
+}
+
+/// Returns the declaration of the function, e.g. `transition mint(public receiver: address) -> Token`,
+/// followed by the declaration of its finalize block, if it has one.
+fn function_signature(function: &Function) -> String {
+    let keyword = match function.variant {
+        Variant::Inline => "inline",
+        Variant::Standard => "function",
+        Variant::Transition => "transition",
+    };
+    let inputs = function.input.iter().map(|input| input.to_string().trim().to_string()).collect::<Vec<_>>();
+    let mut signature =
+        format!("{keyword} {}({}){}", function.identifier, inputs.join(", "), outputs(&function.output));
+
+    if let Some(finalize) = &function.finalize {
+        let inputs = finalize.input.iter().map(|input| input.to_string().trim().to_string()).collect::<Vec<_>>();
+        signature.push_str(&format!(
+            "\nfinalize {}({}){}",
+            finalize.identifier,
+            inputs.join(", "),
+            outputs(&finalize.output)
+        ));
+    }
+    signature
+}
+
+/// Returns the return type of a function, e.g. ` -> (u8, u8)`, or nothing if it returns no values.
+fn outputs(outputs: &[Output]) -> String {
+    let outputs = outputs.iter().map(|output| output.to_string().trim().to_string()).collect::<Vec<_>>();
+    match outputs.len() {
+        0 => String::new(),
+        1 => format!(" -> {}", outputs[0]),
+        _ => format!(" -> ({})", outputs.join(", ")),
+    }
+}
+
+/// Returns the text of the `///` comments between the end of the last declaration before `start` and `start`.
+fn doc_comment(comments: &[Comment], ends: &[BytePos], start: BytePos) -> String {
+    let previous_end = ends.iter().copied().filter(|end| *end <= start).max().unwrap_or(BytePos(0));
+    comments
+        .iter()
+        .filter(|comment| previous_end <= comment.span.lo && comment.span.hi <= start)
+        .filter_map(|comment| comment.content.strip_prefix("///"))
+        .map(|line| line.strip_prefix(' ').unwrap_or(line).trim_end())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Writes the documentation as Markdown.
+fn markdown(program_id: &str, items: &[Item]) -> String {
+    let mut out = format!("# {program_id}\n");
+    for section in SECTIONS {
+        let items = items.iter().filter(|item| item.section == section).collect::<Vec<_>>();
+        if items.is_empty() {
+            continue;
+        }
+
+        out.push_str(&format!("\n## {section}\n"));
+        for item in items {
+            out.push_str(&format!("\n### `{}`\n\n```leo\n{}\n```\n", item.name, item.signature));
+            if !item.doc.is_empty() {
+                out.push_str(&format!("\n{}\n", item.doc));
+            }
+            if let Some(constraints) = item.constraints {
+                out.push_str(&format!("\nEstimated constraints: {constraints}\n"));
+            }
+            if !item.members.is_empty() {
+                out.push('\n');
+                for (signature, doc) in item.members.iter() {
+                    match doc.is_empty() {
+                        true => out.push_str(&format!("- `{signature}`\n")),
+                        false => out.push_str(&format!("- `{signature}`: {}\n", doc.replace('\n', " "))),
+                    }
+                }
+            }
+        }
+    }
+    out
+}
+
+/// Writes the documentation as a standalone HTML page.
+fn html(program_id: &str, items: &[Item]) -> String {
+    let program_id = escape(program_id);
+    let mut out = format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{program_id}</title>\n</head>\n<body>\n<h1>{program_id}</h1>\n"
+    );
+    for section in SECTIONS {
+        let items = items.iter().filter(|item| item.section == section).collect::<Vec<_>>();
+        if items.is_empty() {
+            continue;
+        }
+
+        out.push_str(&format!("<h2>{section}</h2>\n"));
+        for item in items {
+            out.push_str(&format!(
+                "<h3 id=\"{0}\"><code>{0}</code></h3>\n<pre><code>{1}</code></pre>\n",
+                escape(&item.name),
+                escape(&item.signature)
+            ));
+            for paragraph in item.doc.split("\n\n").filter(|paragraph| !paragraph.is_empty()) {
+                out.push_str(&format!("<p>{}</p>\n", escape(paragraph)));
+            }
+            if let Some(constraints) = item.constraints {
+                out.push_str(&format!("<p>Estimated constraints: {constraints}</p>\n"));
+            }
+            if !item.members.is_empty() {
+                out.push_str("<ul>\n");
+                for (signature, doc) in item.members.iter() {
+                    match doc.is_empty() {
+                        true => out.push_str(&format!("<li><code>{}</code></li>\n", escape(signature))),
+                        false => {
+                            out.push_str(&format!("<li><code>{}</code>: {}</li>\n", escape(signature), escape(doc)))
+                        }
+                    }
+                }
+                out.push_str("</ul>\n");
+            }
+        }
+    }
+    out.push_str("</body>\n</html>\n");
+    out
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
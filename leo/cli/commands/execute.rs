@@ -14,9 +14,11 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use super::*;
+use super::{prove::setup, *};
 
-use snarkvm::cli::Execute as SnarkVMExecute;
+use leo_span::Symbol;
+
+use snarkvm::{cli::Execute as SnarkVMExecute, package::Package};
 
 /// Build, Prove and Run Leo program with inputs
 #[derive(Parser, Debug)]
@@ -35,6 +37,9 @@ pub struct Execute {
     )]
     endpoint: String,
 
+    #[clap(long, help = "The variant of the input file section to use, e.g. `testnet` for `[main.testnet]`.")]
+    input_section: Option<String>,
+
     #[clap(flatten)]
     pub(crate) compiler_options: BuildOptions,
 }
@@ -56,7 +61,15 @@ impl Command for Execute {
         // Otherwise, use the input file.
         let mut inputs = match self.inputs.is_empty() {
             true => match input {
-                (Some(input_ast), circuits) => input_ast.program_inputs(&self.name, circuits),
+                (Some(input_ast), structs, functions) => match functions.get(&Symbol::intern(&self.name)) {
+                    Some(function) => input_ast.program_inputs(
+                        function,
+                        self.input_section.as_deref().map(Symbol::intern),
+                        structs,
+                        &context.handler(),
+                    )?,
+                    None => Vec::new(),
+                },
                 _ => Vec::new(),
             },
             false => self.inputs,
@@ -80,6 +93,7 @@ impl Command for Execute {
         // Open the Leo build/ directory
         let path = context.dir()?;
         let build_directory = BuildDirectory::open(&path)?;
+        let keys_directory = OutputsDirectory::create_for(&path, Artifact::Keys)?;
 
         // Change the cwd to the Leo build/ directory to compile aleo files.
         std::env::set_current_dir(&build_directory)
@@ -88,6 +102,10 @@ impl Command for Execute {
         // Unset the Leo panic hook
         let _ = std::panic::take_hook();
 
+        // Synthesize the keys of the circuits that changed, so that the `execute` command does not synthesize them all.
+        let package = Package::<CurrentNetwork>::open(&build_directory).map_err(CliError::failed_to_execute_execute)?;
+        setup(&package, &keys_directory).map_err(CliError::failed_to_execute_execute)?;
+
         // Call the `execute` command.
         println!();
         let command = SnarkVMExecute::try_parse_from(&arguments).map_err(CliError::failed_to_parse_execute)?;
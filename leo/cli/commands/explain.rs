@@ -0,0 +1,52 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use leo_errors::explanations;
+
+/// Explain an error or warning code
+#[derive(Parser, Debug)]
+pub struct Explain {
+    #[clap(name = "CODE", help = "The code of the error or warning, e.g. `ETYC0372003`.")]
+    code: String,
+}
+
+impl Command for Explain {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Leo")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, _: Context, _: Self::Input) -> Result<Self::Output> {
+        let explanation = explanations::explain(&self.code).ok_or_else(|| CliError::unknown_message_code(&self.code))?;
+
+        let kind = if explanation.code.starts_with('E') { "error" } else { "warning" };
+        println!("{}: the `{}` {kind}\n", explanation.code.bold(), explanation.name);
+        match explanation.example {
+            Some(example) => println!("{example}"),
+            None => println!("There is no example of this {kind} yet."),
+        }
+
+        Ok(())
+    }
+}
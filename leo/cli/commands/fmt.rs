@@ -0,0 +1,75 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use leo_package::{imports::ImportsDirectory, source::SourceDirectory};
+use leo_span::{source_map::FileName, symbol::with_session_globals};
+
+use std::fs;
+
+/// Format the Leo source files of the current package.
+#[derive(Parser, Debug)]
+pub struct Fmt {
+    #[clap(long, help = "Check that the files are formatted, without changing them.")]
+    check: bool,
+}
+
+impl Command for Fmt {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Leo")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        let package_path = context.dir()?;
+
+        let mut files = SourceDirectory::files(&package_path)?;
+        if !ImportsDirectory::is_empty(&package_path)? {
+            files.extend(ImportsDirectory::files(&package_path)?);
+        }
+
+        let mut unformatted = 0;
+        for path in files {
+            let source = fs::read_to_string(&path).map_err(|e| CompilerError::file_read_error(&path, e))?;
+            let file = with_session_globals(|s| s.source_map.new_source(&source, FileName::Real(path.clone())));
+            let formatted = leo_parser::format_source(&context.handler(), &file.src, file.start_pos)?;
+            if formatted == source {
+                continue;
+            }
+
+            if self.check {
+                tracing::info!("Would reformat {}", path.display().to_string().dimmed());
+                unformatted += 1;
+            } else {
+                fs::write(&path, formatted).map_err(CliError::failed_to_write_file)?;
+                tracing::info!("Formatted {}", path.display().to_string().dimmed());
+            }
+        }
+
+        if unformatted > 0 {
+            return Err(CliError::files_not_formatted(unformatted).into());
+        }
+
+        Ok(())
+    }
+}
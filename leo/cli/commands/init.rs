@@ -0,0 +1,136 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use std::{fmt, fs, str::FromStr};
+
+/// The placeholder for the package name in the template files.
+const NAME_PLACEHOLDER: &str = "{{NAME}}";
+
+/// Create a new Leo package from a template
+#[derive(Parser, Debug)]
+pub struct Init {
+    #[clap(name = "NAME", help = "Set package name")]
+    pub(crate) name: String,
+
+    #[clap(
+        long,
+        help = "The template of the package, one of `token`, `merkle-membership`, or `signature-check`.",
+        value_parser = Template::from_str
+    )]
+    pub(crate) template: Template,
+}
+
+impl Command for Init {
+    type Input = <New as Command>::Output;
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Leo")
+    }
+
+    fn prelude(&self, context: Context) -> Result<Self::Input> {
+        // Run leo new NAME
+        (New { name: self.name.clone() }).execute(context)
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        let package_dir = context.dir()?;
+
+        // Write the main file.
+        let main_file_path = package_dir.join("src").join("main.leo");
+        fs::write(main_file_path, self.template.main_file_string(&self.name)).map_err(CliError::failed_to_write_file)?;
+
+        // Write the input file.
+        let input_file_path = package_dir.join("inputs").join(format!("{}.in", self.name));
+        fs::write(input_file_path, self.template.input_file_string(&self.name))
+            .map_err(CliError::failed_to_write_file)?;
+
+        tracing::info!(
+            "🚀 Created the '{}' package from the {} template, run `leo test` to check it",
+            self.name.bold(),
+            self.template
+        );
+
+        Ok(())
+    }
+}
+
+/// The templates that a package can be created from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Template {
+    /// A token with public balances and private records.
+    Token,
+    /// A proof of membership in a Merkle tree.
+    MerkleMembership,
+    /// A transition that only accepts orders signed by their owner.
+    SignatureCheck,
+}
+
+impl Template {
+    /// All of the templates.
+    pub const ALL: [Template; 3] = [Template::Token, Template::MerkleMembership, Template::SignatureCheck];
+
+    /// Returns the main file of the template, for a package with the given name.
+    pub fn main_file_string(&self, name: &str) -> String {
+        let template = match self {
+            Self::Token => include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/leo/cli/templates/token/main.leo")),
+            Self::MerkleMembership => {
+                include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/leo/cli/templates/merkle_membership/main.leo"))
+            }
+            Self::SignatureCheck => {
+                include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/leo/cli/templates/signature_check/main.leo"))
+            }
+        };
+        template.replace(NAME_PLACEHOLDER, name)
+    }
+
+    /// Returns the input file of the template, for a package with the given name.
+    pub fn input_file_string(&self, name: &str) -> String {
+        let template = match self {
+            Self::Token => include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/leo/cli/templates/token/inputs.in")),
+            Self::MerkleMembership => {
+                include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/leo/cli/templates/merkle_membership/inputs.in"))
+            }
+            Self::SignatureCheck => {
+                include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/leo/cli/templates/signature_check/inputs.in"))
+            }
+        };
+        template.replace(NAME_PLACEHOLDER, name)
+    }
+}
+
+impl fmt::Display for Template {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Token => write!(f, "token"),
+            Self::MerkleMembership => write!(f, "merkle-membership"),
+            Self::SignatureCheck => write!(f, "signature-check"),
+        }
+    }
+}
+
+impl FromStr for Template {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Self::ALL.into_iter().find(|template| template.to_string() == s).ok_or_else(|| {
+            let names = Self::ALL.iter().map(Template::to_string).collect::<Vec<_>>().join(", ");
+            format!("unknown template `{s}`, expected one of: {names}")
+        })
+    }
+}
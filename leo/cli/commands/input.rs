@@ -0,0 +1,93 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use leo_ast::NodeBuilder;
+use leo_package::{
+    inputs::{InputFile, InputsDirectory},
+    source::{MAIN_FILENAME, SOURCE_DIRECTORY_NAME},
+};
+use leo_span::{source_map::FileName, symbol::with_session_globals};
+
+/// Commands to manage the input file of a Leo program.
+#[derive(Parser, Debug)]
+pub enum Input {
+    /// Writes an input file with a zero value for every input of every transition.
+    Generate {
+        /// Overwrite the input file if it already exists.
+        #[clap(short = 'f', long)]
+        force: bool,
+
+        #[clap(flatten)]
+        compiler_options: BuildOptions,
+    },
+}
+
+impl Command for Input {
+    type Input = <Build as Command>::Output;
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Leo")
+    }
+
+    fn prelude(&self, context: Context) -> Result<Self::Input> {
+        match self {
+            Input::Generate { compiler_options, .. } => (Build { options: compiler_options.clone() }).execute(context),
+        }
+    }
+
+    fn apply(self, context: Context, input: Self::Input) -> Result<Self::Output> {
+        match self {
+            Input::Generate { force, .. } => {
+                let (_, structs, _) = input;
+
+                // The functions are taken from the source, as the compiled functions no longer have the constant
+                // inputs that were inlined from the input file.
+                let package_path = context.dir()?;
+                let path = package_path.join(SOURCE_DIRECTORY_NAME).join(MAIN_FILENAME);
+                let source = std::fs::read_to_string(&path).map_err(|e| CompilerError::file_read_error(&path, e))?;
+                let file = with_session_globals(|s| s.source_map.new_source(&source, FileName::Real(path.clone())));
+                let program =
+                    leo_parser::parse(&context.handler(), &NodeBuilder::default(), &file.src, file.start_pos)?;
+                let functions =
+                    program.program_scopes.into_values().flat_map(|program_scope| program_scope.functions).collect();
+
+                // Write the skeleton to `inputs/package_name.in`.
+                InputsDirectory::create(&package_path)?;
+                let manifest = context.open_manifest()?;
+                let input_file_path = InputFile::new(&manifest.program_id().name().to_string())
+                    .setup_file_path(&package_path)
+                    .into_owned();
+                if input_file_path.exists() && !force {
+                    return Err(PackageError::input_file_already_exists(input_file_path).into());
+                }
+
+                let source = format!(
+                    "// The program input for {}\n{}",
+                    manifest.program_id(),
+                    leo_ast::input_file_skeleton(&functions, &structs)
+                );
+                std::fs::write(&input_file_path, source).map_err(PackageError::io_error_input_file)?;
+
+                tracing::info!("✅ Generated the input file `{}`", input_file_path.display());
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,111 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use leo_compiler::Compiler;
+use leo_package::{
+    build::BuildDirectory,
+    source::{MAIN_FILENAME, SOURCE_DIRECTORY_NAME},
+};
+use leo_passes::{Lint as LintRule, LintConfig, LintLevel};
+
+use std::{fs, path::Path, str::FromStr};
+
+/// The file in the root of the package that configures the linter.
+const LINT_CONFIG_FILENAME: &str = "leo.toml";
+
+/// Lint the current package for zero-knowledge pitfalls
+#[derive(Parser, Debug)]
+pub struct Lint {}
+
+impl Command for Lint {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Leo")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        let package_path = context.dir()?;
+        let manifest = context.open_manifest()?;
+        let program_id = manifest.program_id();
+        let config = lint_config(&package_path)?;
+
+        // Open the build directory.
+        let build_directory = BuildDirectory::open(&package_path)?;
+
+        // Type check and lint the main file.
+        let handler = context.handler();
+        let mut compiler = Compiler::new(
+            program_id.name().to_string(),
+            program_id.network().to_string(),
+            &handler,
+            package_path.join(SOURCE_DIRECTORY_NAME).join(MAIN_FILENAME),
+            build_directory,
+            None,
+        );
+        let denied = compiler.lint(&config)?;
+
+        if denied > 0 {
+            return Err(CliError::lints_denied(denied).into());
+        }
+        tracing::info!(
+            "✅ Linted '{}', and found {} warning(s)",
+            program_id.to_string().bold(),
+            handler.warning_count()
+        );
+
+        Ok(())
+    }
+}
+
+/// Returns the levels of the lints, which are set in the `[lint]` table of `leo.toml`, e.g.
+/// ```toml
+/// [lint]
+/// field_comparison = "deny"
+/// small_domain_hash = "allow"
+/// ```
+/// Lints that are not set, or all of them if the package has no `leo.toml`, are reported as warnings.
+pub(crate) fn lint_config(package_path: &Path) -> Result<LintConfig> {
+    let mut config = LintConfig::default();
+
+    let path = package_path.join(LINT_CONFIG_FILENAME);
+    if !path.exists() {
+        return Ok(config);
+    }
+    let source = fs::read_to_string(&path).map_err(CliError::invalid_lint_config)?;
+    let table: toml::Table = toml::from_str(&source).map_err(CliError::invalid_lint_config)?;
+    let Some(lints) = table.get("lint") else {
+        return Ok(config);
+    };
+
+    let lints = lints.as_table().ok_or_else(|| CliError::invalid_lint_config("`lint` is not a table."))?;
+    for (name, level) in lints {
+        let lint = LintRule::from_str(name).map_err(CliError::invalid_lint_config)?;
+        let level = level
+            .as_str()
+            .ok_or_else(|| CliError::invalid_lint_config(format!("The level of `{name}` is not a string.")))?;
+        config.set(lint, LintLevel::from_str(level).map_err(CliError::invalid_lint_config)?);
+    }
+
+    Ok(config)
+}
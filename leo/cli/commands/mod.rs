@@ -17,37 +17,77 @@
 pub mod account;
 pub use account::Account;
 
+pub mod bench;
+pub use bench::Bench;
+
 pub mod build;
 pub use build::Build;
 
 pub mod clean;
 pub use clean::Clean;
 
+pub mod doc;
+pub use doc::Doc;
+
 pub mod example;
 pub use example::Example;
 
 pub mod execute;
 pub use execute::Execute;
 
+pub mod explain;
+pub use explain::Explain;
+
+pub mod fmt;
+pub use fmt::Fmt;
+
 // pub mod deploy;
 // pub use deploy::Deploy;
 
+pub mod init;
+pub use init::{Init, Template};
+
+pub mod input;
+pub use input::Input;
+
+pub mod lint;
+pub use lint::Lint;
+
 pub mod new;
 pub use new::New;
 
 // pub mod node;
 // pub use node::Node;
 
+pub mod prove;
+pub use prove::Prove;
+
+pub mod repl;
+pub use repl::Repl;
+
 pub mod run;
 pub use run::Run;
 
+pub mod test;
+pub use test::Test;
+
 pub mod update;
 pub use update::Update;
 
+pub mod verify;
+pub use verify::Verify;
+
+pub mod watch;
+pub use watch::Watch;
+
 use super::*;
 use crate::cli::helpers::context::*;
-use leo_errors::{emitter::Handler, CliError, CompilerError, PackageError, Result};
-use leo_package::{build::*, outputs::OutputsDirectory, package::*};
+use leo_errors::{emitter::Handler, CliError, CompilerError, InputError, PackageError, Result};
+use leo_package::{
+    build::*,
+    outputs::{Artifact, OutputsDirectory},
+    package::*,
+};
 
 use clap::Parser;
 use colored::Colorize;
@@ -133,6 +173,8 @@ pub struct BuildOptions {
     pub enable_ast_spans: bool,
     #[clap(long, help = "Enable spans in symbol table snapshots.")]
     pub enable_dce: bool,
+    #[clap(long, help = "Compiles the transitions annotated with `@test` into the program.")]
+    pub enable_tests: bool,
     #[clap(long, help = "Writes all AST snapshots for the different compiler phases.")]
     pub enable_all_ast_snapshots: bool,
     #[clap(long, help = "Writes Input AST snapshot of the initial parse.")]
@@ -151,4 +193,8 @@ pub struct BuildOptions {
     pub enable_inlined_ast_snapshot: bool,
     #[clap(long, help = "Writes AST snapshot of the dead code eliminated (DCE) AST.")]
     pub enable_dce_ast_snapshot: bool,
+    #[clap(long, help = "Treats compiler warnings as errors.")]
+    pub deny_warnings: bool,
+    #[clap(long, help = "Reads inputs from the `.in` or `.json` input file only.", value_parser = ["in", "json"])]
+    pub input_format: Option<String>,
 }
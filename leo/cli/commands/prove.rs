@@ -0,0 +1,402 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{build::synthesize_circuit, *};
+
+use leo_span::Symbol;
+
+use snarkvm::{
+    circuit::{environment::AssignmentVariable, AleoV0},
+    cli::helpers::dotenv_private_key,
+    file::{AVMFile, ProverFile, VerifierFile},
+    ledger::{
+        block::{Block, Execution, Transition},
+        query::Query,
+        store::{helpers::memory::BlockMemory, BlockStore},
+    },
+    package::Package,
+    parameters::testnet3::GenesisBytes,
+    prelude::{
+        FromBytes,
+        Identifier,
+        Locator,
+        Network,
+        PrivateKey,
+        Process,
+        ProgramID,
+        Proof,
+        ProvingKey,
+        ToBytes,
+        Value,
+        VerifyingKey,
+    },
+    synthesizer::program::{CallOperator, Instruction},
+};
+
+use indexmap::{IndexMap, IndexSet};
+use rand_chacha::ChaChaRng;
+use rand_core::SeedableRng;
+use sha2::{Digest, Sha256};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+/// The seed of the inputs that a circuit is synthesized with to compute its checksum.
+const CIRCUIT_SEED: u64 = 0;
+
+/// The extension of the file that holds the proof.
+pub const PROOF_FILE_EXTENSION: &str = "proof";
+
+/// The extension of the file that holds the public inputs of the proof.
+pub const PUBLIC_FILE_EXTENSION: &str = "public.json";
+
+/// The extension of the file that holds the verifying keys of the proof.
+pub const VERIFIER_FILE_EXTENSION: &str = "verifier.json";
+
+/// Prove a transition of the program, and write the proof, its public inputs, and its verifying keys to the outputs directory.
+#[derive(Parser, Debug)]
+pub struct Prove {
+    #[clap(name = "NAME", help = "The name of the transition to prove.", default_value = "main")]
+    name: String,
+
+    #[clap(name = "INPUTS", help = "The inputs to the transition. If none are provided, the input file is used.")]
+    inputs: Vec<String>,
+
+    #[clap(flatten)]
+    pub(crate) compiler_options: BuildOptions,
+}
+
+impl Command for Prove {
+    type Input = <Build as Command>::Output;
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Leo")
+    }
+
+    fn prelude(&self, context: Context) -> Result<Self::Input> {
+        (Build { options: self.compiler_options.clone() }).execute(context)
+    }
+
+    fn apply(self, context: Context, input: Self::Input) -> Result<Self::Output> {
+        // If input values are provided, then prove the transition with those inputs.
+        // Otherwise, use the input file.
+        let (input_ast, structs, functions) = input;
+        let inputs = match (self.inputs.is_empty(), input_ast, functions.get(&Symbol::intern(&self.name))) {
+            (true, Some(input_ast), Some(function)) => {
+                input_ast.program_inputs(function, None, structs, &context.handler())?
+            }
+            (true, ..) => Vec::new(),
+            (false, ..) => self.inputs,
+        };
+
+        // Open the Leo build/ directory
+        let path = context.dir()?;
+        let build_directory = BuildDirectory::open(&path)?;
+        let keys_directory = OutputsDirectory::create_for(&path, Artifact::Keys)?;
+        let proofs_directory = OutputsDirectory::create_for(&path, Artifact::Proofs)?;
+
+        // Change the cwd to the Leo build/ directory to read the private key from its `.env` file.
+        std::env::set_current_dir(&build_directory)
+            .map_err(|err| PackageError::failed_to_set_cwd(build_directory.display(), err))?;
+
+        // Unset the Leo panic hook
+        let _ = std::panic::take_hook();
+
+        let package = Package::<CurrentNetwork>::open(&build_directory).map_err(CliError::failed_to_prove)?;
+        let private_key = dotenv_private_key().map_err(CliError::failed_to_prove)?;
+        let function_name = Identifier::from_str(&self.name).map_err(CliError::failed_to_prove)?;
+        let inputs = inputs
+            .iter()
+            .map(|input| Value::from_str(input))
+            .collect::<snarkvm::prelude::Result<Vec<_>>>()
+            .map_err(CliError::failed_to_prove)?;
+
+        tracing::info!("Proving '{}'...", Locator::new(*package.program_id(), function_name).to_string().bold());
+        let artifacts = prove(&package, &keys_directory, &private_key, function_name, &inputs)
+            .map_err(CliError::failed_to_prove)?;
+
+        let paths = artifacts.write(&proofs_directory, &self.name).map_err(CliError::failed_to_prove)?;
+        for path in paths {
+            tracing::info!("✅ Wrote {}", path.display());
+        }
+
+        Ok(())
+    }
+}
+
+/// A proof of an execution of a transition, along with its public inputs and the verifying keys of its transitions.
+pub struct ProofArtifacts {
+    /// The execution, which holds the proof, and the transitions and global state root that are its public inputs.
+    pub execution: Execution<CurrentNetwork>,
+    /// The verifying key of each transition in the execution, by the locator of its function.
+    pub verifying_keys: IndexMap<Locator<CurrentNetwork>, VerifyingKey<CurrentNetwork>>,
+}
+
+impl ProofArtifacts {
+    /// Writes the proof, the public inputs, and the verifying keys to `NAME.proof`, `NAME.public.json`,
+    /// and `NAME.verifier.json` in the directory, and returns the paths of the files.
+    pub fn write(&self, directory: &Path, name: &str) -> snarkvm::prelude::Result<Vec<PathBuf>> {
+        let proof = match self.execution.proof() {
+            Some(proof) => proof,
+            None => snarkvm::prelude::bail!("The execution has no proof."),
+        };
+        let public = serde_json::json!({
+            "global_state_root": self.execution.global_state_root(),
+            "transitions": self.execution.transitions().collect::<Vec<_>>(),
+        });
+        let verifying_keys = self
+            .verifying_keys
+            .iter()
+            .map(|(locator, verifying_key)| (locator.to_string(), serde_json::json!(verifying_key.to_string())))
+            .collect::<serde_json::Map<_, _>>();
+
+        let files = [
+            (PROOF_FILE_EXTENSION, proof.to_string()),
+            (PUBLIC_FILE_EXTENSION, serde_json::to_string_pretty(&public)?),
+            (VERIFIER_FILE_EXTENSION, serde_json::to_string_pretty(&verifying_keys)?),
+        ];
+        let mut paths = Vec::with_capacity(files.len());
+        for (extension, contents) in files {
+            let path = directory.join(format!("{name}.{extension}"));
+            fs::write(&path, contents)?;
+            paths.push(path);
+        }
+        Ok(paths)
+    }
+
+    /// Reads the proof file, along with the public inputs and, if there are any, the verifying keys next to it.
+    pub fn read(proof_path: &Path) -> snarkvm::prelude::Result<Self> {
+        let read = |path: PathBuf| {
+            fs::read_to_string(&path).map_err(|e| snarkvm::prelude::anyhow!("failed to read `{}`: {e}", path.display()))
+        };
+
+        let proof = Proof::<CurrentNetwork>::from_str(read(proof_path.to_path_buf())?.trim())?;
+        let mut public: serde_json::Value =
+            serde_json::from_str(&read(proof_path.with_extension(PUBLIC_FILE_EXTENSION))?)?;
+        let global_state_root: <CurrentNetwork as Network>::StateRoot =
+            serde_json::from_value(public["global_state_root"].take())?;
+        let transitions: Vec<Transition<CurrentNetwork>> = serde_json::from_value(public["transitions"].take())?;
+        let execution = Execution::from(transitions.into_iter(), global_state_root, Some(proof))?;
+
+        // The verifying keys are optional, as the proof is verified against the keys of the package.
+        let verifier_path = proof_path.with_extension(VERIFIER_FILE_EXTENSION);
+        let verifying_keys: IndexMap<String, String> = match verifier_path.exists() {
+            true => serde_json::from_str(&read(verifier_path)?)?,
+            false => IndexMap::new(),
+        };
+        let verifying_keys = verifying_keys
+            .iter()
+            .map(|(locator, verifying_key)| Ok((Locator::from_str(locator)?, VerifyingKey::from_str(verifying_key)?)))
+            .collect::<snarkvm::prelude::Result<_>>()?;
+
+        Ok(Self { execution, verifying_keys })
+    }
+}
+
+/// Synthesizes the proving and verifying keys of every transition of the package, and of the functions that they
+/// call, and writes them to its build directory. The keys are cached in the keys directory by the checksum of their
+/// circuit, so they are only synthesized again once the circuit changes, rather than whenever the program does.
+pub fn setup(package: &Package<CurrentNetwork>, keys_directory: &Path) -> snarkvm::prelude::Result<()> {
+    let process = package.get_process()?;
+    let build_directory = package.build_directory();
+    fs::create_dir_all(keys_directory)?;
+
+    // The keys of the functions that are called are synthesized first, so that they are not synthesized again
+    // along with the keys of their callers.
+    let mut locations = IndexSet::new();
+    for function_name in package.program().functions().keys() {
+        locations.extend(key_locations(package, &process, *function_name)?);
+    }
+
+    let mut checksums = IndexSet::new();
+    for (program_id, function_name, directory) in locations {
+        let checksum = circuit_checksum(&process, &program_id, &function_name)?;
+        let prover_path = keys_directory.join(format!("{checksum}.prover"));
+        let verifier_path = keys_directory.join(format!("{checksum}.verifier"));
+
+        let (proving_key, verifying_key) = match prover_path.exists() && verifier_path.exists() {
+            true => (
+                ProvingKey::<CurrentNetwork>::from_bytes_le(&fs::read(&prover_path)?)?,
+                VerifyingKey::<CurrentNetwork>::from_bytes_le(&fs::read(&verifier_path)?)?,
+            ),
+            false => {
+                tracing::info!("Synthesizing the keys of '{}'...", Locator::new(program_id, function_name));
+                process.synthesize_key::<AleoV0, _>(&program_id, &function_name, &mut rand::thread_rng())?;
+                let proving_key = process.get_proving_key(program_id, function_name)?;
+                let verifying_key = process.get_verifying_key(program_id, function_name)?;
+                fs::write(&prover_path, proving_key.to_bytes_le()?)?;
+                fs::write(&verifier_path, verifying_key.to_bytes_le()?)?;
+                (proving_key, verifying_key)
+            }
+        };
+        process.insert_proving_key(&program_id, &function_name, proving_key.clone())?;
+        process.insert_verifying_key(&program_id, &function_name, verifying_key.clone())?;
+
+        fs::create_dir_all(&directory)?;
+        ProverFile::create(&directory, &function_name, proving_key)?;
+        VerifierFile::create(&directory, &function_name, verifying_key)?;
+        checksums.insert(checksum);
+    }
+
+    // Remove the keys of circuits that are no longer part of the package.
+    for entry in fs::read_dir(keys_directory)? {
+        let path = entry?.path();
+        if !path.file_stem().and_then(|stem| stem.to_str()).is_some_and(|stem| checksums.contains(stem)) {
+            fs::remove_file(path)?;
+        }
+    }
+
+    // `Package::build` does not synthesize the keys again while the program in the build directory is unchanged.
+    AVMFile::create(&build_directory, package.program().clone(), true)?;
+    Ok(())
+}
+
+/// Returns a SHA256 checksum of the circuit of the function, which only changes when its constraints do.
+/// The circuit is synthesized with inputs sampled from a fixed seed, so that the checksum is deterministic.
+pub fn circuit_checksum(
+    process: &Process<CurrentNetwork>,
+    program_id: &ProgramID<CurrentNetwork>,
+    function_name: &Identifier<CurrentNetwork>,
+) -> snarkvm::prelude::Result<String> {
+    let rng = &mut ChaChaRng::seed_from_u64(CIRCUIT_SEED);
+    let assignments = synthesize_circuit(process, program_id, function_name, rng)?;
+    // The function itself is synthesized after the functions it calls.
+    let assignments = assignments.read();
+    let assignment = match assignments.last() {
+        Some((assignment, _)) => assignment,
+        None => snarkvm::prelude::bail!(
+            "Failed to synthesize the circuit of '{}'.",
+            Locator::new(*program_id, *function_name)
+        ),
+    };
+
+    let mut hasher = Sha256::new();
+    hasher.update(format!("{} {}\n", assignment.num_public(), assignment.num_private()));
+    for (a, b, c) in assignment.constraints() {
+        for lc in [a, b, c] {
+            hasher.update(format!("{}", lc.constant()));
+            for (variable, coefficient) in lc.terms() {
+                let variable = match variable {
+                    AssignmentVariable::Constant(value) => format!("c{value}"),
+                    AssignmentVariable::Public(index) => format!("p{index}"),
+                    AssignmentVariable::Private(index) => format!("w{index}"),
+                };
+                hasher.update(format!(" + {coefficient}*{variable}"));
+            }
+            hasher.update(";");
+        }
+        hasher.update("\n");
+    }
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// Executes the transition with the given inputs and proves the execution, using the keys cached in the keys directory.
+pub fn prove(
+    package: &Package<CurrentNetwork>,
+    keys_directory: &Path,
+    private_key: &PrivateKey<CurrentNetwork>,
+    function_name: Identifier<CurrentNetwork>,
+    inputs: &[Value<CurrentNetwork>],
+) -> snarkvm::prelude::Result<ProofArtifacts> {
+    setup(package, keys_directory)?;
+
+    let rng = &mut rand::thread_rng();
+    let program_id = package.program_id();
+    let process = package.get_process()?;
+    load_keys(package, &process, function_name)?;
+
+    let authorization = process.authorize::<AleoV0, _>(private_key, program_id, function_name, inputs.iter(), rng)?;
+    let (_, mut trace) = process.execute::<AleoV0>(authorization)?;
+    trace.prepare(genesis_query()?)?;
+    let locator = Locator::new(*program_id, function_name);
+    let execution = trace.prove_execution::<AleoV0, _>(&locator.to_string(), rng)?;
+
+    let verifying_keys = execution
+        .transitions()
+        .map(|transition| {
+            let locator = Locator::new(*transition.program_id(), *transition.function_name());
+            Ok((locator, process.get_verifying_key(transition.program_id(), transition.function_name())?))
+        })
+        .collect::<snarkvm::prelude::Result<_>>()?;
+
+    Ok(ProofArtifacts { execution, verifying_keys })
+}
+
+/// Loads the cached keys of the transition, and of the functions that it calls, into the process.
+fn load_keys(
+    package: &Package<CurrentNetwork>,
+    process: &Process<CurrentNetwork>,
+    function_name: Identifier<CurrentNetwork>,
+) -> snarkvm::prelude::Result<()> {
+    for (program_id, function_name, directory) in key_locations(package, process, function_name)? {
+        let prover = ProverFile::open(&directory, &function_name)?;
+        process.insert_proving_key(&program_id, &function_name, prover.proving_key().clone())?;
+        let verifier = VerifierFile::open(&directory, &function_name)?;
+        process.insert_verifying_key(&program_id, &function_name, verifier.verifying_key().clone())?;
+    }
+    Ok(())
+}
+
+/// Returns the functions that the transition calls, followed by the transition itself, along with the
+/// directories that their keys are written to. The functions of `credits.aleo` are left out, as their keys are
+/// the official parameters, which snarkVM loads when they are needed.
+fn key_locations(
+    package: &Package<CurrentNetwork>,
+    process: &Process<CurrentNetwork>,
+    function_name: Identifier<CurrentNetwork>,
+) -> snarkvm::prelude::Result<Vec<(ProgramID<CurrentNetwork>, Identifier<CurrentNetwork>, PathBuf)>> {
+    let program = process.get_program(package.program_id())?;
+    let mut locations = Vec::new();
+    for instruction in program.get_function(&function_name)?.instructions() {
+        if let Instruction::Call(call) = instruction {
+            let (program, resource) = match call.operator() {
+                CallOperator::Locator(locator) => (process.get_program(locator.program_id())?, locator.resource()),
+                CallOperator::Resource(resource) => (program, resource),
+            };
+            if program.contains_function(resource) && !is_credits(program.id()) {
+                locations.push((*program.id(), *resource, key_directory(package, program.id())));
+            }
+        }
+    }
+    locations.push((*program.id(), function_name, key_directory(package, program.id())));
+    Ok(locations)
+}
+
+/// Returns whether the program is `credits.aleo`, whose keys must not be synthesized again, as the network only
+/// accepts proofs made with its official parameters.
+pub fn is_credits(program_id: &ProgramID<CurrentNetwork>) -> bool {
+    program_id.name().to_string() == "credits" && program_id.network().to_string() == "aleo"
+}
+
+/// Returns the directory that the keys of the functions of the program are written to.
+/// The keys of an imported program are written to its own directory within the build directory.
+pub fn key_directory(package: &Package<CurrentNetwork>, program_id: &ProgramID<CurrentNetwork>) -> PathBuf {
+    match program_id == package.program_id() {
+        true => package.build_directory(),
+        false => package.build_directory().join(format!("{}-{}", program_id.name(), program_id.network())),
+    }
+}
+
+/// Returns a query of a ledger that holds only the genesis block.
+/// Proving requires a global state root, which is taken from this ledger.
+pub(crate) fn genesis_query() -> snarkvm::prelude::Result<Query<CurrentNetwork, BlockMemory<CurrentNetwork>>> {
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<CurrentNetwork>>::open(None)?;
+    block_store.insert(&Block::from_bytes_le(GenesisBytes::load_bytes())?)?;
+    Ok(Query::from(block_store))
+}
@@ -0,0 +1,357 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use leo_ast::{Expression, Node, NodeBuilder, Program, ProgramScope, ReturnStatement, Statement, TupleType, Type};
+use leo_compiler::Compiler;
+use leo_errors::{emitter::BufferEmitter, LeoError};
+use leo_package::source::{MAIN_FILENAME, SOURCE_DIRECTORY_NAME};
+use leo_passes::{format_value, Interpreter, TypeTable};
+use leo_span::{source_map::FileName, symbol::with_session_globals, Symbol};
+
+use indexmap::IndexMap;
+
+use snarkvm::{
+    circuit::AleoV0,
+    prelude::{Assignments, CallStack, Identifier, PrivateKey, Process, StackExecute, Value},
+};
+
+use std::{
+    fs,
+    io::{self, BufRead, Write},
+    path::PathBuf,
+    str::FromStr,
+};
+
+/// The name of the transition whose body holds the statements entered so far.
+const SESSION: &str = "repl_session";
+
+/// The name of the transition that is synthesized to count the constraints of an expression.
+const CONSTRAINTS: &str = "repl_constraints";
+
+const HELP: &str =
+    "Enter a statement such as `let a: u8 = 1u8;` to add it to the session, or an expression to evaluate it.
+Inside a package, the structs, constants, and non-transition functions of its program are in scope.
+
+Commands:
+  :constraints <expression>  Count the constraints of synthesizing the expression, with the variables as private inputs
+  :help                      Print this message
+  :quit                      Exit the REPL";
+
+/// Type check and evaluate Leo statements and expressions interactively, without synthesizing circuits.
+#[derive(Parser, Debug)]
+pub struct Repl {}
+
+impl Command for Repl {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Leo")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        // Outside of a package, the statements are evaluated in an empty program.
+        let mut session = match context.open_manifest() {
+            Ok(manifest) => {
+                let path = context.dir()?.join(SOURCE_DIRECTORY_NAME).join(MAIN_FILENAME);
+                let program = fs::read_to_string(&path).map_err(|e| CompilerError::file_read_error(&path, e))?;
+                Session::new(manifest.program_id().name().to_string(), &program)?
+            }
+            Err(_) => Session::new("repl".to_string(), "program repl.aleo {}")?,
+        };
+
+        println!("Leo REPL. Type `:help` for help.");
+        let mut lines = io::stdin().lock().lines();
+        loop {
+            print!("> ");
+            io::stdout().flush().map_err(CliError::cli_io_error)?;
+            let line = match lines.next() {
+                Some(line) => line.map_err(CliError::cli_io_error)?,
+                None => break,
+            };
+
+            let line = line.trim();
+            let (command, argument) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+            let output = match command {
+                "" => continue,
+                ":quit" => break,
+                ":help" => Ok(HELP.to_string()),
+                ":constraints" => session.constraints(argument.trim()).map(|count| format!("{count} constraint(s)")),
+                _ if command.starts_with(':') => Ok(format!("Unknown command `{command}`. Type `:help` for help.")),
+                // Statements end with a semicolon, except for conditionals and loops.
+                _ if line.ends_with(';') || command == "if" || command == "for" => {
+                    session.statement(line).map(|()| String::new())
+                }
+                _ => session.expression(line).map(|value| display(&value)),
+            };
+
+            match output {
+                Ok(output) if output.is_empty() => {}
+                Ok(output) => println!("{output}"),
+                Err(error) => eprintln!("{error}"),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The statements entered so far, along with the program that they are evaluated in.
+pub(crate) struct Session {
+    /// The name of the program.
+    program_name: String,
+    /// The source of the program.
+    program: String,
+    /// The offset of the closing brace of the program scope, before which the session transition is inserted.
+    scope_end: usize,
+    /// The statements entered so far, each of which type checked and evaluated successfully.
+    statements: Vec<String>,
+    /// The values of the variables after evaluating the statements entered so far.
+    variables: IndexMap<Symbol, leo_ast::Value>,
+}
+
+impl Session {
+    /// Returns an empty session for the program with the given source.
+    pub(crate) fn new(program_name: String, program: &str) -> Result<Self> {
+        let (handler, buffer) = Handler::new_with_buf();
+        let file = with_session_globals(|s| s.source_map.new_source(program, FileName::Custom("repl".into())));
+        let ast = leo_parser::parse(&handler, &NodeBuilder::default(), &file.src, file.start_pos)
+            .map_err(|error| first_error(buffer, error))?;
+
+        // Parsing guarantees that a program has exactly one program scope, whose span ends with its closing brace.
+        let span = ast.program_scopes.values().next().unwrap().span;
+        let scope_end = (span.hi.0 - file.start_pos.0) as usize - 1;
+        Ok(Self {
+            program_name,
+            program: file.src.clone(),
+            scope_end,
+            statements: Vec::new(),
+            variables: IndexMap::new(),
+        })
+    }
+
+    /// Checks and evaluates the statement, adding it to the session if it succeeds.
+    pub(crate) fn statement(&mut self, statement: &str) -> Result<()> {
+        let variables = self.evaluate(&self.session(statement, None), |interpreter, last| {
+            interpreter.evaluate_statement(last)?;
+            Ok(interpreter.variables().clone())
+        })?;
+        self.statements.push(statement.to_string());
+        self.variables = variables;
+        Ok(())
+    }
+
+    /// Checks and evaluates the expression.
+    pub(crate) fn expression(&self, expression: &str) -> Result<leo_ast::Value> {
+        self.evaluate_expression(expression, |interpreter, expression| interpreter.evaluate_expression(expression))
+    }
+
+    /// Counts the constraints of synthesizing the expression, excluding those of the inputs and outputs.
+    /// The variables of the session are private inputs, so that the expression is not folded into a constant.
+    fn constraints(&self, expression: &str) -> Result<u64> {
+        let (output, variables) = self.evaluate_expression(expression, |interpreter, expression| {
+            Ok((interpreter.evaluate_expression(expression)?, interpreter.variables().clone()))
+        })?;
+
+        // Only the variables that can be written as literals are passed as inputs.
+        let inputs = variables
+            .iter()
+            .filter_map(|(name, value)| Some((*name, Type::from(value), format_value(value)?)))
+            .collect::<Vec<_>>();
+        let parameters = inputs.iter().map(|(name, type_, _)| format!("{name}: {type_}")).collect::<Vec<_>>();
+        let transition = format!(
+            "transition {CONSTRAINTS}({}) -> {} {{\nreturn {expression};\n}}",
+            parameters.join(", "),
+            Type::from(&output)
+        );
+
+        let (handler, buffer) = Handler::new_with_buf();
+        let mut compiler = self.compiler(&handler);
+        let (_, bytecode) = compiler
+            .compile_from_string(&self.insert(&transition), FileName::Custom("repl".into()))
+            .map_err(|error| first_error(buffer, error))?;
+
+        let inputs = inputs.into_iter().map(|(_, _, literal)| literal).collect::<Vec<_>>();
+        count_constraints(&bytecode, &inputs).map_err(|error| CliError::failed_to_count_constraints(error).into())
+    }
+
+    /// Checks `expression` as the output of the session transition, and evaluates it with `evaluate`,
+    /// with the variables of the session in scope.
+    fn evaluate_expression<T>(
+        &self,
+        expression: &str,
+        evaluate: impl FnOnce(&mut Interpreter, &Expression) -> Result<T>,
+    ) -> Result<T> {
+        let output_type = self.output_type(expression)?;
+        let program = self.session(&format!("return {expression};"), Some(&output_type.to_string()));
+        self.evaluate(&program, |interpreter, last| match last {
+            Statement::Return(ReturnStatement { expression, .. }) => evaluate(interpreter, expression),
+            _ => unreachable!("The last statement is the return of the expression."),
+        })
+    }
+
+    /// Returns the type of `expression`.
+    /// Leo does not infer the types of outputs, so the expression is first checked as the output of the session
+    /// transition with a placeholder output type, and its type is the one that the type checker recorded for it.
+    /// A tuple is only checked against a tuple type, so the placeholder for a tuple is a tuple of booleans,
+    /// and its type is made up of the types recorded for its elements.
+    fn output_type(&self, expression: &str) -> Result<Type> {
+        let statement = format!("return {expression};");
+        let (program, type_table, checked) = self.type_check(&self.session(&statement, None))?;
+        let arity = match returned(&program) {
+            Expression::Tuple(tuple) => tuple.elements.len(),
+            expression => {
+                return match (type_table.get(&expression.id()), checked) {
+                    (Some(type_), _) => Ok(type_),
+                    // Only the unit expression is returned from the session transition without a mismatch.
+                    (None, Ok(())) => Ok(Type::Unit),
+                    (None, Err(error)) => Err(error),
+                };
+            }
+        };
+
+        let placeholder = format!("({})", vec!["bool"; arity].join(", "));
+        let (program, type_table, checked) = self.type_check(&self.session(&statement, Some(&placeholder)))?;
+        let elements: Option<Vec<Type>> = match returned(&program) {
+            Expression::Tuple(tuple) => tuple.elements.iter().map(|element| type_table.get(&element.id())).collect(),
+            _ => unreachable!("The same expression is returned from both session transitions."),
+        };
+        match (elements, checked) {
+            (Some(elements), _) => Ok(Type::Tuple(TupleType::new(elements))),
+            (None, checked) => checked.map(|()| Type::Unit),
+        }
+    }
+
+    /// Checks the program with the session transition, and evaluates the last statement of the transition
+    /// with `evaluate`, with the variables of the session in scope.
+    /// The other statements were evaluated when they were entered, so they are not evaluated again.
+    fn evaluate<T>(
+        &self,
+        program: &str,
+        evaluate: impl FnOnce(&mut Interpreter, &Statement) -> Result<T>,
+    ) -> Result<T> {
+        let (program, _, checked) = self.type_check(program)?;
+        checked?;
+
+        // Parsing guarantees that a program has exactly one program scope.
+        let scope = program.program_scopes.values().next().unwrap();
+        let mut interpreter = Interpreter::from_program_scope(scope)?;
+        *interpreter.variables_mut() = self.variables.clone();
+        evaluate(&mut interpreter, session_statements(scope).last().unwrap())
+    }
+
+    /// Returns the program with the session transition, whose body is the statements entered so far followed by
+    /// `statement`, and whose output has the given type.
+    fn session(&self, statement: &str, output_type: Option<&str>) -> String {
+        let output = output_type.map(|output_type| format!(" -> {output_type}")).unwrap_or_default();
+        let body = self.statements.iter().map(String::as_str).chain([statement]).collect::<Vec<_>>();
+        self.insert(&format!("transition {SESSION}(){output} {{\n{}\n}}", body.join("\n")))
+    }
+
+    /// Returns the program with `item` inserted at the end of its program scope.
+    fn insert(&self, item: &str) -> String {
+        format!("{}\n{item}\n{}", &self.program[..self.scope_end], &self.program[self.scope_end..])
+    }
+
+    /// Returns a compiler for the program, which is compiled from source and never written to disk.
+    fn compiler<'a>(&self, handler: &'a Handler) -> Compiler<'a> {
+        Compiler::new(self.program_name.clone(), "aleo".to_string(), handler, PathBuf::new(), PathBuf::new(), None)
+    }
+
+    /// Parses and type checks the program, returning it along with the types that the type checker recorded,
+    /// and the result of type checking.
+    fn type_check(&self, source: &str) -> Result<(Program, TypeTable, Result<()>)> {
+        let (handler, buffer) = Handler::new_with_buf();
+        let mut compiler = self.compiler(&handler);
+        compiler
+            .parse_program_from_string(source, FileName::Custom("repl".into()))
+            .map_err(|e| first_error(buffer.clone(), e))?;
+        let symbol_table = compiler.symbol_table_pass().map_err(|e| first_error(buffer.clone(), e))?;
+        let checked = compiler.type_checker_pass(symbol_table).map(|_| ()).map_err(|e| first_error(buffer, e));
+        Ok((compiler.ast.as_repr().clone(), compiler.type_table().clone(), checked))
+    }
+}
+
+/// Returns the statements of the session transition in the program scope.
+fn session_statements(scope: &ProgramScope) -> &[Statement] {
+    let session = Symbol::intern(SESSION);
+    // The session transition is always inserted into the program.
+    let (_, function) = scope.functions.iter().find(|(name, _)| *name == session).unwrap();
+    &function.block.statements
+}
+
+/// Returns the expression that the session transition of the program returns.
+fn returned(program: &Program) -> &Expression {
+    // Parsing guarantees that a program has exactly one program scope.
+    match session_statements(program.program_scopes.values().next().unwrap()).last() {
+        Some(Statement::Return(ReturnStatement { expression, .. })) => expression,
+        _ => unreachable!("The last statement of the session transition is the return of the expression."),
+    }
+}
+
+/// Writes the value in Leo syntax, e.g. `Point { x: 1u8, y: 2u8 }`.
+pub(crate) fn display(value: &leo_ast::Value) -> String {
+    let join = |values: &mut dyn Iterator<Item = String>| values.collect::<Vec<_>>().join(", ");
+    match value {
+        leo_ast::Value::Struct(name, members) => {
+            format!(
+                "{name} {{ {} }}",
+                join(&mut members.iter().map(|(name, value)| format!("{name}: {}", display(value))))
+            )
+        }
+        leo_ast::Value::Tuple(values) => format!("({})", join(&mut values.iter().map(display))),
+        leo_ast::Value::Array(values) => format!("[{}]", join(&mut values.iter().map(display))),
+        value => format_value(value).unwrap_or_else(|| value.to_string()),
+    }
+}
+
+/// Returns the first error that was emitted, or `error` if there is none.
+fn first_error(buffer: BufferEmitter, error: LeoError) -> LeoError {
+    buffer.extract_errs().into_inner().into_iter().next().unwrap_or(error)
+}
+
+/// Runs the transition that returns the expression with the given inputs, and returns the number of
+/// constraints that were synthesized for its body.
+fn count_constraints(bytecode: &str, inputs: &[String]) -> snarkvm::prelude::Result<u64> {
+    let program = snarkvm::prelude::Program::<CurrentNetwork>::from_str(bytecode)?;
+    let mut process = Process::<CurrentNetwork>::load()?;
+    process.add_program(&program)?;
+
+    let rng = &mut rand::thread_rng();
+    let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+    let inputs = inputs.iter().map(|input| Value::<CurrentNetwork>::from_str(input)).collect::<Result<Vec<_>, _>>()?;
+    let authorization = process.authorize::<AleoV0, _>(
+        &private_key,
+        program.id(),
+        Identifier::from_str(CONSTRAINTS)?,
+        inputs.iter(),
+        rng,
+    )?;
+
+    // Running the transition, rather than executing it, synthesizes its circuit without proving it.
+    let assignments = Assignments::<CurrentNetwork>::default();
+    let call_stack = CallStack::PackageRun(vec![authorization.next()?], private_key, assignments.clone());
+    process.get_stack(program.id())?.execute_function::<AleoV0>(call_stack, None)?;
+
+    let metrics = assignments.read().last().map(|(_, metrics)| metrics.num_function_constraints);
+    Ok(metrics.unwrap_or_default())
+}
@@ -16,7 +16,17 @@
 
 use super::*;
 
-use snarkvm::cli::Run as SnarkVMRun;
+use leo_ast::{Function, Mode};
+use leo_span::Symbol;
+
+use snarkvm::{
+    circuit::AleoV0,
+    cli::{helpers::dotenv_private_key, Run as SnarkVMRun},
+    package::Package,
+    prelude::{Identifier, Value},
+};
+
+use std::{path::Path, str::FromStr};
 
 /// Build, Prove and Run Leo program with inputs
 #[derive(Parser, Debug)]
@@ -27,6 +37,17 @@ pub struct Run {
     #[clap(name = "INPUTS", help = "The inputs to the program. If none are provided, the input file is used.")]
     inputs: Vec<String>,
 
+    #[clap(long, help = "The variant of the input file section to use, e.g. `testnet` for `[main.testnet]`.")]
+    input_section: Option<String>,
+
+    #[clap(
+        long,
+        help = "The format of the outputs. With `json`, the outputs are also written to `outputs/runs/NAME.outputs.json`.",
+        default_value = "text",
+        value_parser = ["text", "json"]
+    )]
+    output_format: String,
+
     #[clap(flatten)]
     pub(crate) compiler_options: BuildOptions,
 }
@@ -46,19 +67,29 @@ impl Command for Run {
     fn apply(self, context: Context, input: Self::Input) -> Result<Self::Output> {
         // If input values are provided, then run the program with those inputs.
         // Otherwise, use the input file.
-        let mut inputs = match self.inputs.is_empty() {
-            true => match input {
-                (Some(input_ast), circuits) => input_ast.program_inputs(&self.name, circuits),
+        let (input_ast, structs, functions) = input;
+        let function = functions.get(&Symbol::intern(&self.name));
+        let inputs = match self.inputs.is_empty() {
+            true => match input_ast {
+                Some(input_ast) => match function {
+                    Some(function) => input_ast.program_inputs(
+                        function,
+                        self.input_section.as_deref().map(Symbol::intern),
+                        structs,
+                        &context.handler(),
+                    )?,
+                    None => Vec::new(),
+                },
                 _ => Vec::new(),
             },
             false => self.inputs,
         };
 
         // Compose the `run` command.
-        let mut arguments = vec![SNARKVM_COMMAND.to_string(), self.name];
+        let mut arguments = vec![SNARKVM_COMMAND.to_string(), self.name.clone()];
 
         // Add the program inputs to the arguments.
-        arguments.append(&mut inputs);
+        arguments.extend_from_slice(&inputs);
 
         // Open the Leo build/ directory
         let path = context.dir()?;
@@ -71,6 +102,11 @@ impl Command for Run {
         // Unset the Leo panic hook
         let _ = std::panic::take_hook();
 
+        // Run the program directly, so that its outputs can be written in a structured form.
+        if self.output_format == "json" {
+            return run_with_json_outputs(&self.name, &inputs, function, &path);
+        }
+
         // Call the `run` command.
         println!();
         let command = SnarkVMRun::try_parse_from(&arguments).map_err(CliError::failed_to_parse_run)?;
@@ -82,3 +118,72 @@ impl Command for Run {
         Ok(())
     }
 }
+
+/// Runs the function `name` of the program in the current directory, logs its outputs,
+/// and writes them to `outputs/runs/NAME.outputs.json` in the package at `package_path`.
+fn run_with_json_outputs(
+    name: &str,
+    inputs: &[String],
+    function: Option<&Function>,
+    package_path: &Path,
+) -> Result<()> {
+    let package = Package::<CurrentNetwork>::open(&std::env::current_dir().map_err(CliError::failed_to_execute_run)?)
+        .map_err(CliError::failed_to_execute_run)?;
+    let private_key = dotenv_private_key().map_err(CliError::failed_to_execute_run)?;
+    let function_name = Identifier::from_str(name).map_err(CliError::failed_to_parse_run)?;
+    let inputs = inputs
+        .iter()
+        .map(|input| Value::from_str(input))
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .map_err(CliError::failed_to_parse_run)?;
+
+    let (response, _) = package
+        .run::<AleoV0, _>(&private_key, function_name, &inputs, &mut rand::thread_rng())
+        .map_err(CliError::failed_to_execute_run)?;
+
+    for value in response.outputs() {
+        tracing::info!(" • {value}");
+    }
+
+    // Write the outputs to the outputs directory.
+    let outputs_path = OutputsDirectory::create_for(package_path, Artifact::Runs)?.join(format!("{name}.outputs.json"));
+    let json = outputs_json(&package.program_id().to_string(), name, response.outputs(), function);
+    std::fs::write(&outputs_path, serde_json::to_string_pretty(&json).expect("JSON values are serializable"))
+        .map_err(|e| CliError::failed_to_execute_run(format!("failed to write `{}`: {e}", outputs_path.display())))?;
+
+    tracing::info!("✅ Wrote the outputs to {}", outputs_path.display());
+    Ok(())
+}
+
+/// Returns the JSON document of the `outputs` of the function `name` of `program`, which is declared as `function`.
+/// Each output has its position, type, mode, and value, and whether it is a plaintext, record, or future.
+/// The type and mode are `null` if the declaration is not known.
+pub(crate) fn outputs_json(
+    program: &str,
+    name: &str,
+    outputs: &[Value<CurrentNetwork>],
+    function: Option<&Function>,
+) -> serde_json::Value {
+    let outputs = outputs
+        .iter()
+        .enumerate()
+        .map(|(index, value)| {
+            let output = function.and_then(|function| function.output.get(index));
+            serde_json::json!({
+                "index": index,
+                "type": output.map(|output| output.type_().to_string()),
+                "mode": output.map(|output| match output.mode() {
+                    Mode::None => Mode::Private.to_string(),
+                    mode => mode.to_string(),
+                }),
+                "value": value.to_string(),
+                "encoding": match value {
+                    Value::Plaintext(_) => "plaintext",
+                    Value::Record(_) => "record",
+                    Value::Future(_) => "future",
+                },
+            })
+        })
+        .collect::<Vec<_>>();
+    serde_json::json!({ "program": program, "function": name, "outputs": outputs })
+}
@@ -0,0 +1,223 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use leo_ast::Function;
+use leo_passes::{parse_value, Interpreter};
+use leo_span::Symbol;
+
+use indexmap::IndexMap;
+use snarkvm::{
+    circuit::{AleoV0, Environment},
+    cli::helpers::dotenv_private_key,
+    package::Package,
+    prelude::{Identifier, PrivateKey, Value},
+};
+
+use std::{any::Any, panic::AssertUnwindSafe, str::FromStr};
+
+/// Build the program with its `@test` transitions and run each of them.
+#[derive(Parser, Debug)]
+pub struct Test {
+    #[clap(name = "FILTER", help = "Only run the tests whose name contains this string.")]
+    filter: Option<String>,
+
+    #[clap(long, help = "Evaluate the tests with the interpreter instead of synthesizing their circuits.")]
+    interpret: bool,
+
+    #[clap(flatten)]
+    pub(crate) compiler_options: BuildOptions,
+}
+
+/// The outcome of running a single test.
+#[derive(Debug)]
+pub(crate) enum Outcome {
+    /// The test ran to completion, synthesizing the given number of constraints if it was not interpreted.
+    Passed(Option<u64>),
+    /// An assertion of the test failed.
+    Failed(String),
+    /// The test could not be run.
+    Errored(String),
+}
+
+impl Command for Test {
+    type Input = <Build as Command>::Output;
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Leo")
+    }
+
+    fn prelude(&self, context: Context) -> Result<Self::Input> {
+        let mut options = self.compiler_options.clone();
+        options.enable_tests = true;
+        (Build { options }).execute(context)
+    }
+
+    fn apply(self, context: Context, input: Self::Input) -> Result<Self::Output> {
+        let (input_ast, structs, functions) = input;
+
+        // Collect the tests to run, along with their inputs from the input file.
+        let mut tests = Vec::new();
+        for (name, function) in functions.iter().filter(|(_, function)| function.is_test()) {
+            if self.filter.as_ref().is_some_and(|filter| !name.to_string().contains(filter.as_str())) {
+                continue;
+            }
+            let inputs = match &input_ast {
+                Some(input_ast) => input_ast.program_inputs(function, None, structs.clone(), &context.handler())?,
+                None => Vec::new(),
+            };
+            tests.push((*name, inputs, function.is_should_fail_test()));
+        }
+
+        tracing::info!("Running {} test(s)", tests.len());
+
+        let outcomes = match self.interpret {
+            true => interpret_tests(&functions, &tests),
+            false => run_tests(&context, &tests)?,
+        };
+
+        let mut failed = 0;
+        // A `@test(should_fail)` test passes only if one of its assertions fails.
+        for ((name, _, should_fail), outcome) in tests.iter().zip(outcomes) {
+            match (outcome, should_fail) {
+                (Outcome::Passed(None), false) => tracing::info!("✅ {name}"),
+                (Outcome::Passed(Some(constraints)), false) => tracing::info!("✅ {name} ({constraints} constraints)"),
+                (Outcome::Failed(_), true) => tracing::info!("✅ {name} (failed as expected)"),
+                (Outcome::Passed(_), true) => {
+                    failed += 1;
+                    tracing::error!("❌ {name}: expected an assertion to fail, but the test passed");
+                }
+                (Outcome::Failed(error), false) | (Outcome::Errored(error), _) => {
+                    failed += 1;
+                    tracing::error!("❌ {name}: {error}");
+                }
+            }
+        }
+
+        tracing::info!("{} passed, {failed} failed", tests.len() - failed);
+
+        match failed {
+            0 => Ok(()),
+            _ => Err(CliError::tests_failed(failed, tests.len()).into()),
+        }
+    }
+}
+
+/// Runs each test with snarkVM, synthesizing its circuit.
+fn run_tests(context: &Context, tests: &[(Symbol, Vec<String>, bool)]) -> Result<Vec<Outcome>> {
+    // Open the Leo build/ directory
+    let path = context.dir()?;
+    let build_directory = BuildDirectory::open(&path)?;
+
+    // Change the cwd to the Leo build/ directory to run the program.
+    std::env::set_current_dir(&build_directory)
+        .map_err(|err| PackageError::failed_to_set_cwd(build_directory.display(), err))?;
+
+    let package = Package::<CurrentNetwork>::open(&build_directory).map_err(CliError::failed_to_execute_run)?;
+    let private_key = dotenv_private_key().map_err(CliError::failed_to_execute_run)?;
+
+    // Failed assertions on constants panic during synthesis, so silence the panic hook while the tests run.
+    let hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(|_| {}));
+    let outcomes =
+        tests.iter().map(|(name, inputs, _)| run_test(&package, &private_key, &name.to_string(), inputs)).collect();
+    std::panic::set_hook(hook);
+
+    Ok(outcomes)
+}
+
+/// Evaluates each test with the interpreter, without synthesizing a circuit.
+fn interpret_tests(functions: &IndexMap<Symbol, Function>, tests: &[(Symbol, Vec<String>, bool)]) -> Vec<Outcome> {
+    let mut interpreter = Interpreter::new(functions.iter().map(|(name, function)| (*name, function)));
+    tests
+        .iter()
+        .map(|(name, inputs, _)| {
+            let function = &functions[name];
+            let arguments = inputs
+                .iter()
+                .zip(&function.input)
+                .map(|(input, declaration)| parse_value(input, &declaration.type_(), function.span))
+                .collect::<Result<Vec<_>>>();
+            let arguments = match arguments {
+                Ok(arguments) => arguments,
+                Err(error) => return Outcome::Errored(error.to_string()),
+            };
+            // Overflows and other failed checks would leave the constraints unsatisfied, just like failed assertions.
+            match interpreter.call(*name, arguments) {
+                Ok(_) => Outcome::Passed(None),
+                Err(error) if Interpreter::is_unsupported(&error) => Outcome::Errored(error.to_string()),
+                Err(error) => Outcome::Failed(error.to_string()),
+            }
+        })
+        .collect()
+}
+
+/// Runs the test transition `name` with the given inputs, synthesizing its circuit.
+/// A test fails if the constraints of its circuit are not satisfied, or if an assertion on constants fails.
+/// It errors if its inputs cannot be parsed, or if it cannot be run for any other reason.
+pub(crate) fn run_test(
+    package: &Package<CurrentNetwork>,
+    private_key: &PrivateKey<CurrentNetwork>,
+    name: &str,
+    inputs: &[String],
+) -> Outcome {
+    let function_name = match Identifier::from_str(name) {
+        Ok(function_name) => function_name,
+        Err(error) => return Outcome::Errored(error.to_string()),
+    };
+    let inputs = match inputs.iter().map(|input| Value::from_str(input)).collect::<snarkvm::prelude::Result<Vec<_>>>() {
+        Ok(inputs) => inputs,
+        Err(error) => return Outcome::Errored(error.to_string()),
+    };
+
+    AleoV0::reset();
+    let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+        package.run::<AleoV0, _>(private_key, function_name, &inputs, &mut rand::thread_rng())
+    }));
+    let outcome = match result {
+        Ok(Ok((_, call_metrics))) => Outcome::Passed(Some(
+            call_metrics
+                .iter()
+                .map(|metrics| {
+                    metrics.num_request_constraints
+                        + metrics.num_function_constraints
+                        + metrics.num_response_constraints
+                })
+                .sum(),
+        )),
+        // snarkVM leaves an unsatisfied circuit in place when it rejects it.
+        Ok(Err(error)) if AleoV0::num_constraints() > 0 && !AleoV0::is_satisfied() => {
+            Outcome::Failed(error.to_string())
+        }
+        Ok(Err(error)) => Outcome::Errored(error.to_string()),
+        Err(payload) => Outcome::Failed(panic_message(payload)),
+    };
+    AleoV0::reset();
+    outcome
+}
+
+/// Returns the message of a panic raised during synthesis.
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => "the circuit panicked during synthesis".to_string(),
+        },
+    }
+}
@@ -0,0 +1,94 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+    prove::{is_credits, key_directory, setup, ProofArtifacts},
+    *,
+};
+
+use snarkvm::{
+    file::VerifierFile,
+    package::Package,
+    prelude::{bail, Locator},
+};
+
+use std::path::{Path, PathBuf};
+
+/// Verify a proof that was written by `leo prove`, against the verifying keys of the program in the build directory.
+#[derive(Parser, Debug)]
+pub struct Verify {
+    #[clap(
+        name = "PROOF",
+        help = "The proof file to verify. Its public inputs are read from the file next to it.",
+        default_value = "outputs/proofs/main.proof"
+    )]
+    proof: PathBuf,
+}
+
+impl Command for Verify {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Leo")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        // Open the Leo build/ directory, which holds the program that the proof is verified against.
+        let path = context.dir()?;
+        let build_directory = BuildDirectory::open(&path)?;
+        let keys_directory = OutputsDirectory::create_for(&path, Artifact::Keys)?;
+        let package = Package::<CurrentNetwork>::open(&build_directory).map_err(CliError::failed_to_verify)?;
+
+        let proof_path = path.join(&self.proof);
+        let artifacts = ProofArtifacts::read(&proof_path).map_err(CliError::failed_to_verify)?;
+        verify(&package, &keys_directory, &artifacts).map_err(CliError::failed_to_verify)?;
+
+        tracing::info!("✅ Verified {}", proof_path.display());
+        Ok(())
+    }
+}
+
+/// Verifies the proof against its public inputs and the verifying keys of the package, which are set up in its
+/// build directory as by `leo prove`. The proof is rejected if the verifying keys written next to it, which
+/// are only informational, differ from those of the package.
+pub fn verify(
+    package: &Package<CurrentNetwork>,
+    keys_directory: &Path,
+    artifacts: &ProofArtifacts,
+) -> snarkvm::prelude::Result<()> {
+    setup(package, keys_directory)?;
+
+    let process = package.get_process()?;
+    for transition in artifacts.execution.transitions() {
+        let (program_id, function_name) = (transition.program_id(), transition.function_name());
+        let locator = Locator::new(*program_id, *function_name);
+        // The official keys of `credits.aleo` are loaded with the process, rather than set up in the build directory.
+        let verifying_key = match is_credits(program_id) {
+            true => process.get_verifying_key(program_id, function_name)?,
+            false => VerifierFile::open(&key_directory(package, program_id), function_name)?.verifying_key().clone(),
+        };
+        if artifacts.verifying_keys.get(&locator).is_some_and(|supplied| *supplied != verifying_key) {
+            bail!("The verifying key of '{locator}' written next to the proof does not match the program.");
+        }
+        process.insert_verifying_key(program_id, function_name, verifying_key)?;
+    }
+    process.verify_execution(&artifacts.execution)
+}
@@ -0,0 +1,143 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use leo_errors::{emitter::BufferEmitter, Diagnostic};
+use leo_package::{imports::IMPORTS_DIRECTORY_NAME, inputs::INPUTS_DIRECTORY_NAME, source::SOURCE_DIRECTORY_NAME};
+
+use indexmap::IndexMap;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+/// Rebuild the package whenever its source, import, or input files change.
+#[derive(Parser, Debug)]
+pub struct Watch {
+    #[clap(long, help = "How often to check for changes, in milliseconds.", default_value = "500")]
+    pub(crate) interval: u64,
+    #[clap(flatten)]
+    pub(crate) options: BuildOptions,
+}
+
+impl Command for Watch {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Leo")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        let package_path = context.dir()?;
+        // Fail early if this is not a package, rather than waiting for files that never appear.
+        context.open_manifest()?;
+
+        let mut snapshot = modification_times(&package_path);
+        let mut reported = Vec::new();
+        self.rebuild(&context, &mut reported);
+
+        loop {
+            thread::sleep(Duration::from_millis(self.interval));
+
+            let current = modification_times(&package_path);
+            if current == snapshot {
+                continue;
+            }
+            snapshot = current;
+
+            tracing::info!("Change detected, rebuilding");
+            self.rebuild(&context, &mut reported);
+        }
+    }
+}
+
+impl Watch {
+    /// Builds the package, reporting rather than returning any error so that watching continues.
+    /// Only the errors and warnings that are not in `reported`, those of the previous build, are written,
+    /// and those of the previous build that are gone are noted as resolved.
+    fn rebuild(&self, context: &Context, reported: &mut Vec<Diagnostic>) {
+        let buffer = BufferEmitter::new();
+        let build_context = Context { buffer: Some(buffer.clone()), ..context.clone() };
+        let result = (Build { options: self.options.clone() }).execute(build_context);
+        let built = result.is_ok();
+
+        let handler = context.handler();
+        let mut diagnostics = Vec::new();
+        for warning in buffer.extract_warnings().into_inner() {
+            let diagnostic = warning.diagnostic();
+            if !reported.contains(&diagnostic) {
+                handler.emit_warning(warning);
+            }
+            diagnostics.push(diagnostic);
+        }
+        // Errors that were emitted while building are returned as the code of the last one, which has no diagnostic.
+        let mut errors = buffer.extract_errs().into_inner();
+        errors.extend(result.err());
+        for error in errors {
+            if let Some(diagnostic) = error.diagnostic() {
+                if !reported.contains(&diagnostic) {
+                    handler.emit_err(error);
+                }
+                diagnostics.push(diagnostic);
+            }
+        }
+        for diagnostic in reported.iter().filter(|diagnostic| !diagnostics.contains(diagnostic)) {
+            match &diagnostic.code {
+                Some(code) => tracing::info!("Resolved [{code}]: {}", diagnostic.message),
+                None => tracing::info!("Resolved: {}", diagnostic.message),
+            }
+        }
+        *reported = diagnostics;
+
+        match built {
+            true => tracing::info!("Watching for changes"),
+            false => tracing::info!("Build failed, watching for changes"),
+        }
+    }
+}
+
+/// Returns the modification time of every file in the source, imports, and inputs directories.
+/// Missing directories and unreadable files are skipped, since they may be in the middle of being written.
+fn modification_times(package_path: &Path) -> IndexMap<PathBuf, SystemTime> {
+    let mut times = IndexMap::new();
+    for directory in [SOURCE_DIRECTORY_NAME, IMPORTS_DIRECTORY_NAME, INPUTS_DIRECTORY_NAME] {
+        collect_modification_times(&package_path.join(directory), &mut times);
+    }
+    times
+}
+
+fn collect_modification_times(path: &Path, times: &mut IndexMap<PathBuf, SystemTime>) {
+    let entries = match fs::read_dir(path) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_modification_times(&path, times);
+        } else if let Ok(modified) = entry.metadata().and_then(|metadata| metadata.modified()) {
+            times.insert(path, modified);
+        }
+    }
+}
@@ -15,7 +15,12 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::*;
-use leo_errors::{CliError, PackageError, Result};
+use leo_errors::{
+    emitter::{BufferEmitter, Handler, JsonEmitter},
+    CliError,
+    PackageError,
+    Result,
+};
 use leo_package::build::{BuildDirectory, BUILD_DIRECTORY_NAME};
 
 use snarkvm::file::Manifest;
@@ -25,19 +30,55 @@ use std::{
     fs::File,
     io::Write,
     path::{Path, PathBuf},
+    str::FromStr,
 };
 
+/// The format that errors and warnings are written in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MessageFormat {
+    /// Formatted for people to read, with the source lines that they are about.
+    Human,
+    /// A JSON object per line, as described by `leo_errors::Diagnostic`.
+    Json,
+}
+
+impl FromStr for MessageFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "human" => Ok(Self::Human),
+            "json" => Ok(Self::Json),
+            _ => Err(format!("unknown message format `{s}`, expected one of: human, json")),
+        }
+    }
+}
+
 /// Project context, manifest, current directory etc
 /// All the info that is relevant in most of the commands
 #[derive(Clone)]
 pub struct Context {
     /// Path at which the command is called, None when default
     pub path: Option<PathBuf>,
+    /// The format that errors and warnings are written in.
+    pub message_format: MessageFormat,
+    /// Collects errors and warnings instead of writing them, if set.
+    pub buffer: Option<BufferEmitter>,
 }
 
 impl Context {
-    pub fn new(path: Option<PathBuf>) -> Result<Context> {
-        Ok(Context { path })
+    pub fn new(path: Option<PathBuf>, message_format: MessageFormat) -> Result<Context> {
+        Ok(Context { path, message_format, buffer: None })
+    }
+
+    /// Returns a handler that writes errors and warnings in the message format of the context,
+    /// or collects them in its buffer if it has one.
+    pub fn handler(&self) -> Handler {
+        match (&self.buffer, self.message_format) {
+            (Some(buffer), _) => Handler::new(Box::new(buffer.clone())),
+            (None, MessageFormat::Human) => Handler::default(),
+            (None, MessageFormat::Json) => Handler::new(Box::<JsonEmitter>::default()),
+        }
     }
 
     /// Returns the path to the Leo package.
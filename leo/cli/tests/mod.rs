@@ -14,7 +14,35 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use leo_errors::Result;
+use crate::cli::{
+    commands::{
+        build::ManifestFields,
+        lint::{lint_config, Lint},
+        repl::{display, Session},
+        run::outputs_json,
+        test::{run_test, Outcome},
+        Command,
+    },
+    context::{Context, MessageFormat},
+    CurrentNetwork,
+    Template,
+};
+use leo_ast::NodeBuilder;
+use leo_compiler::{BuildOptions, Compiler, CompilerOptions};
+use leo_errors::{emitter::Handler, Result};
+use leo_passes::{Lint as LintRule, LintLevel};
+use leo_span::{
+    source_map::FileName,
+    symbol::{create_session_if_not_set_then, with_session_globals},
+};
+
+use indexmap::IndexMap;
+use snarkvm::{
+    package::Package,
+    prelude::{PrivateKey, Value},
+};
+
+use std::{path::PathBuf, str::FromStr};
 // use std::path::PathBuf;
 
 /* use crate::{
@@ -44,6 +72,116 @@ pub fn format_event() -> Result<()> {
     Ok(())
 }
 
+#[test]
+pub fn templates_compile() -> Result<()> {
+    create_session_if_not_set_then(|_| {
+        for template in Template::ALL {
+            let name = "template";
+            let handler = Handler::default();
+
+            // Compile the main file along with its `@test` transitions.
+            let options = CompilerOptions {
+                build: BuildOptions { dce_enabled: true, tests_enabled: true },
+                output: Default::default(),
+            };
+            let mut compiler = Compiler::new(
+                name.to_string(),
+                "aleo".to_string(),
+                &handler,
+                PathBuf::new(),
+                PathBuf::new(),
+                Some(options),
+            );
+            let (symbol_table, bytecode) = compiler.compile_from_string(
+                &template.main_file_string(name),
+                FileName::Custom(format!("{template}/main.leo")),
+            )?;
+            let functions = compiler
+                .ast
+                .ast
+                .program_scopes
+                .into_values()
+                .flat_map(|program_scope| program_scope.functions)
+                .collect::<IndexMap<_, _>>();
+
+            // Parse the input file, and check each of its sections against the transition it is for.
+            let input_string = template.input_file_string(name);
+            let input_file = with_session_globals(|s| {
+                s.source_map.new_source(&input_string, FileName::Custom(format!("{template}/{name}.in")))
+            });
+            let input_ast =
+                leo_parser::parse_input(&handler, &NodeBuilder::default(), &input_file.src, input_file.start_pos)?;
+            let mut inputs = IndexMap::new();
+            for section in input_ast.sections.iter() {
+                let function = functions
+                    .get(&section.name)
+                    .unwrap_or_else(|| panic!("`{template}` has inputs for a missing transition `{}`", section.name));
+                let values = input_ast.program_inputs(function, None, symbol_table.structs.clone(), &handler)?;
+                inputs.insert(section.name, values);
+            }
+
+            // Run the `@test` transitions, which only pass if a `should_fail` test fails.
+            let directory = tempfile::tempdir().unwrap();
+            std::fs::write(directory.path().join("program.json"), format!(r#"{{ "program": "{name}.aleo" }}"#))
+                .unwrap();
+            std::fs::write(directory.path().join("main.aleo"), bytecode).unwrap();
+            let package = Package::<CurrentNetwork>::open(directory.path()).unwrap();
+            let private_key = PrivateKey::new(&mut rand::thread_rng()).unwrap();
+            for (test, function) in functions.iter().filter(|(_, function)| function.is_test()) {
+                let inputs = inputs.get(test).cloned().unwrap_or_default();
+                let outcome = run_test(&package, &private_key, &test.to_string(), &inputs);
+                match function.is_should_fail_test() {
+                    false => assert!(matches!(outcome, Outcome::Passed(_)), "`{template}/{test}`: {outcome:?}"),
+                    true => assert!(matches!(outcome, Outcome::Failed(_)), "`{template}/{test}`: {outcome:?}"),
+                }
+            }
+        }
+        Ok(())
+    })
+}
+
+#[test]
+pub fn run_outputs_json() -> Result<()> {
+    create_session_if_not_set_then(|_| {
+        let handler = Handler::default();
+        let program = "
+program test.aleo {
+    transition main(a: u8) -> (u8, public u32) {
+        return (a, 2u32);
+    }
+}";
+        let mut compiler =
+            Compiler::new("test".to_string(), "aleo".to_string(), &handler, PathBuf::new(), PathBuf::new(), None);
+        compiler.compile_from_string(program, FileName::Custom("run-test".into()))?;
+        let function = &compiler.ast.ast.program_scopes.values().next().unwrap().functions[0].1;
+
+        // Each output has the type and mode of its declaration.
+        let outputs: Vec<Value<CurrentNetwork>> =
+            vec![Value::from_str("1u8").unwrap(), Value::from_str("2u32").unwrap()];
+        assert_eq!(
+            outputs_json("test.aleo", "main", &outputs, Some(function)),
+            serde_json::json!({
+                "program": "test.aleo",
+                "function": "main",
+                "outputs": [
+                    { "index": 0, "type": "u8", "mode": "private", "value": "1u8", "encoding": "plaintext" },
+                    { "index": 1, "type": "u32", "mode": "public", "value": "2u32", "encoding": "plaintext" },
+                ],
+            })
+        );
+
+        // Without a declaration, the type and mode are unknown.
+        let record =
+            "{ owner: aleo1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq3ljyzc.private, _nonce: 0group.public }";
+        let outputs: Vec<Value<CurrentNetwork>> = vec![Value::from_str(record).unwrap()];
+        let json = outputs_json("test.aleo", "mint", &outputs, None);
+        assert_eq!(json["outputs"][0]["type"], serde_json::Value::Null);
+        assert_eq!(json["outputs"][0]["mode"], serde_json::Value::Null);
+        assert_eq!(json["outputs"][0]["encoding"], "record");
+        Ok(())
+    })
+}
+
 // todo (collin): uncomment after refactor
 // #[test]
 // pub fn build_pedersen_hash() -> Result<()> {
@@ -225,3 +363,172 @@ pub fn format_event() -> Result<()> {
 
     Ok(context)
 } */
+
+#[test]
+pub fn run_test_outcomes() -> Result<()> {
+    create_session_if_not_set_then(|_| {
+        let handler = Handler::default();
+        let program = "
+program test.aleo {
+    transition satisfied(a: u8) {
+        assert_eq(a, 1u8);
+    }
+
+    transition unsatisfied(a: u8) {
+        assert_eq(a, 2u8);
+    }
+
+    transition constant_assertion(a: u8) {
+        assert_eq(1u8, 2u8);
+    }
+}";
+        let mut compiler =
+            Compiler::new("test".to_string(), "aleo".to_string(), &handler, PathBuf::new(), PathBuf::new(), None);
+        let (_, bytecode) = compiler.compile_from_string(program, FileName::Custom("test-test".into()))?;
+
+        let directory = tempfile::tempdir().unwrap();
+        std::fs::write(directory.path().join("program.json"), r#"{ "program": "test.aleo" }"#).unwrap();
+        std::fs::write(directory.path().join("main.aleo"), bytecode).unwrap();
+        let package = Package::<CurrentNetwork>::open(directory.path()).unwrap();
+        let private_key = PrivateKey::new(&mut rand::thread_rng()).unwrap();
+        let inputs = ["1u8".to_string()];
+
+        assert!(matches!(
+            run_test(&package, &private_key, "satisfied", &inputs),
+            Outcome::Passed(Some(constraints)) if constraints > 0
+        ));
+        assert!(matches!(run_test(&package, &private_key, "unsatisfied", &inputs), Outcome::Failed(_)));
+        // An assertion on constants fails while the circuit is synthesized, rather than leaving it unsatisfied.
+        assert!(matches!(run_test(&package, &private_key, "constant_assertion", &inputs), Outcome::Failed(_)));
+        // Inputs that do not parse are not test failures.
+        assert!(matches!(run_test(&package, &private_key, "satisfied", &["1".to_string()]), Outcome::Errored(_)));
+        Ok(())
+    })
+}
+
+#[test]
+pub fn repl_session() -> Result<()> {
+    create_session_if_not_set_then(|_| {
+        // The closing brace in the comment after the program scope is not the end of the scope.
+        let program = "
+program test.aleo {
+    struct Point {
+        x: u8,
+        y: u8,
+    }
+
+    function double(a: u8) -> (u8, u8) {
+        return (a, a);
+    }
+}
+// }";
+        let mut session = Session::new("test".to_string(), program)?;
+        session.statement("let a: u8 = 1u8;")?;
+        session.statement("let p: Point = Point { x: a, y: 2u8 };")?;
+
+        assert_eq!(display(&session.expression("a + p.y")?), "3u8");
+        // Tuples are evaluated, whether they are written out or returned from a function.
+        assert_eq!(display(&session.expression("(a, p.x == a)")?), "(1u8, true)");
+        assert_eq!(display(&session.expression("double(a)")?), "(1u8, 1u8)");
+
+        // A statement that fails is not added to the session.
+        assert!(session.statement("let b: u8 = a - 2u8;").is_err());
+        assert!(session.expression("b").is_err());
+        Ok(())
+    })
+}
+
+#[test]
+pub fn manifest_fields() {
+    let directory = tempfile::tempdir().unwrap();
+    let path = directory.path().join("program.json");
+
+    std::fs::write(&path, r#"{ "program": "test.aleo", "leo_version": "1.10.0", "max_constraints": 100000 }"#).unwrap();
+    let fields = ManifestFields::read(&path).unwrap();
+    assert_eq!(fields.leo_version.as_deref(), Some("1.10.0"));
+    assert_eq!(fields.max_constraints, Some(100000));
+
+    std::fs::write(&path, r#"{ "program": "test.aleo" }"#).unwrap();
+    assert_eq!(ManifestFields::read(&path).unwrap().max_constraints, None);
+
+    // Invalid budgets are reported rather than ignored.
+    for budget in [r#""100000""#, "-1", "1.5"] {
+        std::fs::write(&path, format!(r#"{{ "program": "test.aleo", "max_constraints": {budget} }}"#)).unwrap();
+        let error = ManifestFields::read(&path).unwrap_err();
+        assert_eq!(error.error_code(), "EPAK0375039", "{budget}");
+    }
+}
+
+#[test]
+pub fn lint_configs() {
+    let directory = tempfile::tempdir().unwrap();
+    let path = directory.path().join("leo.toml");
+
+    // Without a `leo.toml`, every lint is a warning.
+    let config = lint_config(directory.path()).unwrap();
+    assert!(LintRule::ALL.iter().all(|lint| config.level(*lint) == LintLevel::Warn));
+
+    std::fs::write(&path, "[lint]\nfield_comparison = \"deny\"\nsmall_domain_hash = \"allow\"\n").unwrap();
+    let config = lint_config(directory.path()).unwrap();
+    assert_eq!(config.level(LintRule::FieldComparison), LintLevel::Deny);
+    assert_eq!(config.level(LintRule::SmallDomainHash), LintLevel::Allow);
+    assert_eq!(config.level(LintRule::ConstantAssertion), LintLevel::Warn);
+
+    // Unknown lints and levels, levels that are not strings, and a `lint` key that is not a table are reported.
+    for source in [
+        "[lint]\nfield_compare = \"deny\"\n",
+        "[lint]\nfield_comparison = \"forbid\"\n",
+        "[lint]\nfield_comparison = true\n",
+        "lint = 1\n",
+        "[lint\n",
+    ] {
+        std::fs::write(&path, source).unwrap();
+        let error = lint_config(directory.path()).unwrap_err();
+        assert_eq!(error.error_code(), "ECLI0377030", "{source}");
+    }
+}
+
+#[test]
+pub fn lint_package_with_import() {
+    let directory = tempfile::tempdir().unwrap();
+    let package_path = directory.path();
+    std::fs::write(package_path.join("program.json"), r#"{ "program": "test.aleo", "version": "0.0.0" }"#).unwrap();
+    std::fs::create_dir_all(package_path.join("src")).unwrap();
+    std::fs::create_dir_all(package_path.join("imports")).unwrap();
+    std::fs::write(
+        package_path.join("imports").join("token.leo"),
+        "
+program token.aleo {
+    transition mint(a: u64) -> u64 {
+        return a;
+    }
+}",
+    )
+    .unwrap();
+    std::fs::write(
+        package_path.join("src").join("main.leo"),
+        "
+import token.leo;
+
+program test.aleo {
+    transition main(a: field, b: field) -> bool {
+        return a < b;
+    }
+}",
+    )
+    .unwrap();
+
+    // Imports are resolved from the current directory, as they are when the package is built.
+    let previous = std::env::current_dir().unwrap();
+    std::env::set_current_dir(package_path).unwrap();
+    let context = Context::new(Some(package_path.to_path_buf()), MessageFormat::Human).unwrap();
+
+    // The field comparison is a warning, unless it is denied in `leo.toml`.
+    let warned = Lint {}.apply(context.clone(), ());
+    std::fs::write(package_path.join("leo.toml"), "[lint]\nfield_comparison = \"deny\"\n").unwrap();
+    let denied = Lint {}.apply(context, ());
+
+    std::env::set_current_dir(previous).unwrap();
+    assert!(warned.is_ok(), "{warned:?}");
+    assert_eq!(denied.unwrap_err().error_code(), "ECLI0377031");
+}
@@ -71,7 +71,8 @@ impl ImportsDirectory {
             .is_none())
     }
 
-    /// Returns a list of files in the imports directory.
+    /// Returns a list of Leo files in the imports directory.
+    /// The manifests of the imported packages, e.g. `foo.json` next to `foo.leo`, are not included.
     pub fn files(path: &Path) -> Result<Vec<PathBuf>> {
         let mut path = Cow::from(path);
         if path.is_dir() && !path.ends_with(IMPORTS_DIRECTORY_NAME) {
@@ -81,8 +82,15 @@ impl ImportsDirectory {
         let directory = fs::read_dir(&path).map_err(|err| PackageError::failed_to_read_file(path.display(), err))?;
         let mut file_paths = Vec::new();
 
-        parse_file_paths(directory, &mut file_paths)?;
+        parse_file_paths(directory, &mut file_paths, &["json"])?;
 
         Ok(file_paths)
     }
+
+    /// Returns the path to the manifest of the imported package whose Leo file is `file_path`, if it has one.
+    /// The manifest is the `program.json` of the imported package, copied to e.g. `foo.json` next to `foo.leo`.
+    pub fn manifest(file_path: &Path) -> Option<PathBuf> {
+        let path = file_path.with_extension("json");
+        path.exists().then_some(path)
+    }
 }
@@ -32,6 +32,7 @@ use std::{
 };
 
 pub static INPUT_FILE_EXTENSION: &str = ".in";
+pub static JSON_INPUT_FILE_EXTENSION: &str = ".json";
 
 #[derive(Deserialize)]
 pub struct InputFile {
@@ -91,4 +92,16 @@ b: u32 = 2u32;
         }
         path
     }
+
+    /// Returns the path of the JSON input file, which is used when there is no `.in` file.
+    pub fn setup_json_file_path<'a>(&self, path: &'a Path) -> Cow<'a, Path> {
+        let mut path = Cow::from(path);
+        if path.is_dir() {
+            if !path.ends_with(INPUTS_DIRECTORY_NAME) {
+                path.to_mut().push(INPUTS_DIRECTORY_NAME);
+            }
+            path.to_mut().push(format!("{}{JSON_INPUT_FILE_EXTENSION}", self.package_name));
+        }
+        path
+    }
 }
@@ -31,7 +31,13 @@ use std::{fs, fs::ReadDir, path::PathBuf};
 
 pub static LEO_FILE_EXTENSION: &str = ".leo";
 
-pub(crate) fn parse_file_paths(directory: ReadDir, file_paths: &mut Vec<PathBuf>) -> Result<()> {
+/// Collects the paths of the Leo files in `directory` and its subdirectories into `file_paths`.
+/// Files with an extension in `ignored_extensions` are skipped, and any other file is an error.
+pub(crate) fn parse_file_paths(
+    directory: ReadDir,
+    file_paths: &mut Vec<PathBuf>,
+    ignored_extensions: &[&str],
+) -> Result<()> {
     for file_entry in directory {
         let file_entry = file_entry.map_err(PackageError::failed_to_get_leo_file_entry)?;
         let file_path = file_entry.path();
@@ -41,13 +47,16 @@ pub(crate) fn parse_file_paths(directory: ReadDir, file_paths: &mut Vec<PathBuf>
             let directory =
                 fs::read_dir(&file_path).map_err(|err| PackageError::failed_to_read_file(file_path.display(), err))?;
 
-            parse_file_paths(directory, file_paths)?;
+            parse_file_paths(directory, file_paths, ignored_extensions)?;
             continue;
         } else {
             // Verify that the file has the Leo file extension
             let file_extension = file_path
                 .extension()
                 .ok_or_else(|| PackageError::failed_to_get_leo_file_extension(file_path.as_os_str().to_owned()))?;
+            if ignored_extensions.iter().any(|extension| file_extension == *extension) {
+                continue;
+            }
             if file_extension != LEO_FILE_EXTENSION.trim_start_matches('.') {
                 return Err(PackageError::invalid_leo_file_extension(
                     file_path.as_os_str().to_owned(),
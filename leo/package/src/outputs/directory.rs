@@ -18,12 +18,66 @@ use leo_errors::{PackageError, Result};
 
 use std::{
     borrow::Cow,
+    fmt,
     fs,
     path::{Path, PathBuf},
+    str::FromStr,
 };
 
 pub static OUTPUTS_DIRECTORY_NAME: &str = "outputs/";
 
+/// A kind of artifact in the outputs directory. Each kind is kept in its own subdirectory, so that it can be
+/// removed without touching the others.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Artifact {
+    /// The AST and symbol table snapshots of the compiler, in `outputs/ast/`.
+    Ast,
+    /// The results of `leo bench`, which include the constraint counts of the transitions, in `outputs/bench/`.
+    Bench,
+    /// The documentation of the program, in `outputs/docs/`.
+    Docs,
+    /// The proving and verifying keys of each circuit, cached by its checksum, in `outputs/keys/`.
+    Keys,
+    /// The proofs, their public inputs, and their verifying keys, in `outputs/proofs/`.
+    Proofs,
+    /// The outputs of `leo run`, in `outputs/runs/`.
+    Runs,
+}
+
+impl Artifact {
+    /// Every kind of artifact.
+    pub const ALL: [Self; 6] = [Self::Ast, Self::Bench, Self::Docs, Self::Keys, Self::Proofs, Self::Runs];
+
+    /// Returns the name of the subdirectory of the artifact.
+    pub fn directory_name(&self) -> &'static str {
+        match self {
+            Self::Ast => "ast",
+            Self::Bench => "bench",
+            Self::Docs => "docs",
+            Self::Keys => "keys",
+            Self::Proofs => "proofs",
+            Self::Runs => "runs",
+        }
+    }
+}
+
+impl fmt::Display for Artifact {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.directory_name())
+    }
+}
+
+impl FromStr for Artifact {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL.into_iter().find(|artifact| artifact.directory_name() == s).ok_or_else(|| {
+            let names = Self::ALL.iter().map(Self::directory_name).collect::<Vec<_>>().join(", ");
+            format!("unknown artifact `{s}`, expected one of: {names}")
+        })
+    }
+}
+
 pub struct OutputsDirectory;
 
 impl OutputsDirectory {
@@ -38,6 +92,28 @@ impl OutputsDirectory {
         Ok(path.to_path_buf())
     }
 
+    /// Creates the subdirectory of the artifact in the outputs directory at the provided path.
+    pub fn create_for(path: &Path, artifact: Artifact) -> Result<PathBuf> {
+        let path = Self::create(path)?.join(artifact.directory_name());
+        fs::create_dir_all(&path).map_err(|err| PackageError::failed_to_create_directory(path.display(), err))?;
+        Ok(path)
+    }
+
+    /// Removes the subdirectory of the artifact in the outputs directory at the provided path.
+    pub fn remove_artifact(path: &Path, artifact: Artifact) -> Result<String> {
+        let mut path = Cow::from(path);
+        if path.is_dir() && !path.ends_with(OUTPUTS_DIRECTORY_NAME) {
+            path.to_mut().push(OUTPUTS_DIRECTORY_NAME);
+        }
+        path.to_mut().push(artifact.directory_name());
+
+        if path.exists() {
+            fs::remove_dir_all(&path).map_err(|e| PackageError::failed_to_remove_directory(path.display(), e))?;
+        }
+
+        Ok(format!("(in \"{}\")", path.display()))
+    }
+
     /// Removes the directory at the provided path.
     pub fn remove(path: &Path) -> Result<String> {
         let mut path = Cow::from(path);
@@ -51,7 +51,7 @@ impl SourceDirectory {
         let directory = fs::read_dir(&path).map_err(|err| PackageError::failed_to_read_file(path.display(), err))?;
         let mut file_paths = Vec::new();
 
-        parse_file_paths(directory, &mut file_paths)?;
+        parse_file_paths(directory, &mut file_paths, &[])?;
 
         Ok(file_paths)
     }
@@ -88,7 +88,7 @@ fn new_compiler(handler: &Handler) -> Compiler<'_> {
         PathBuf::from(String::new()),
         PathBuf::from(String::new()),
         Some(CompilerOptions {
-            build: BuildOptions { dce_enabled: true },
+            build: BuildOptions { dce_enabled: true, tests_enabled: false },
             output: OutputOptions {
                 symbol_table_spans_enabled: false,
                 initial_symbol_table: false,